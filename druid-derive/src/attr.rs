@@ -116,10 +116,6 @@ impl Fields<LensAttrs> {
 }
 
 impl<Attrs> Fields<Attrs> {
-    pub fn len(&self) -> usize {
-        self.fields.len()
-    }
-
     pub fn iter(&self) -> impl Iterator<Item = &Field<Attrs>> {
         self.fields.iter()
     }
@@ -182,6 +178,12 @@ impl Field<DataAttrs> {
                 }
             }
         }
+        if ignore && same_fn.is_some() {
+            return Err(Error::new(
+                field.span(),
+                "The 'ignore' and 'same_fn' data attributes can't be combined",
+            ));
+        }
         Ok(Field {
             ident,
             ty,
@@ -295,8 +297,17 @@ fn parse_lit_into_expr_path(lit: &syn::Lit) -> Result<ExprPath, Error> {
         ));
     };
 
-    let tokens = syn::parse_str(&string.value())?;
-    syn::parse2(tokens)
+    // `LitStr::parse` gives the resulting tokens the span of the literal, so
+    // if the path doesn't resolve, the compiler points at the attribute.
+    string.parse().map_err(|_| {
+        Error::new(
+            string.span(),
+            format!(
+                "expected a path to a function, found \"{}\"",
+                string.value()
+            ),
+        )
+    })
 }
 
 fn parse_lit_into_ident(lit: &syn::Lit) -> Result<Ident, Error> {
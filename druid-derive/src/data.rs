@@ -42,7 +42,8 @@ fn derive_struct(
 
     let fields = Fields::<DataAttrs>::parse_ast(&s.fields)?;
 
-    let diff = if fields.len() > 0 {
+    // if every field is ignored, all values are the same
+    let diff = if fields.iter().any(|f| !f.attrs.ignore) {
         let same_fns = fields
             .iter()
             .filter(|f| !f.attrs.ignore)
@@ -88,9 +89,12 @@ fn derive_enum(
     let (_, ty_generics, where_clause) = &input.generics.split_for_impl();
 
     if is_c_style_enum(&s) {
+        // no fields, so the variant is all there is to compare
         let res = quote! {
             impl<#impl_generics> ::druid::Data for #ident #ty_generics #where_clause {
-                fn same(&self, other: &Self) -> bool { self == other }
+                fn same(&self, other: &Self) -> bool {
+                    ::std::mem::discriminant(self) == ::std::mem::discriminant(other)
+                }
             }
         };
         return Ok(res);
@@ -114,6 +118,11 @@ fn derive_enum(
                     quote!( #same_fn(#var_left, #var_right) )
                 })
                 .collect();
+            let tests = if tests.is_empty() {
+                quote!(true)
+            } else {
+                quote!( #( #tests )&&* )
+            };
 
             if let FieldKind::Named = fields.kind {
                 let lefts: Vec<_> = fields
@@ -135,7 +144,7 @@ fn derive_enum(
 
                 Ok(quote! {
                     (#ident :: #variant { #( #lefts ),* }, #ident :: #variant { #( #rights ),* }) => {
-                        #( #tests )&&*
+                        #tests
                     }
                 })
             } else {
@@ -151,7 +160,7 @@ fn derive_enum(
                 if fields.iter().count() > 0 {
                     Ok(quote! {
                         ( #ident :: #variant( #(#vars_left),* ),  #ident :: #variant( #(#vars_right),* )) => {
-                            #( #tests )&&*
+                            #tests
                         }
                     })
                 } else {
@@ -37,18 +37,19 @@ pub(crate) fn derive_lens_impl(
 fn derive_struct(input: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, syn::Error> {
     let ty = &input.ident;
 
-    let fields = if let syn::Data::Struct(syn::DataStruct { fields, .. }) = &input.data {
-        Fields::<LensAttrs>::parse_ast(fields)?
-    } else {
-        return Err(syn::Error::new(
-            input.span(),
-            "Lens implementations can only be derived from structs with named fields",
-        ));
-    };
+    let (fields, fields_span) =
+        if let syn::Data::Struct(syn::DataStruct { fields, .. }) = &input.data {
+            (Fields::<LensAttrs>::parse_ast(fields)?, fields.span())
+        } else {
+            return Err(syn::Error::new(
+                input.span(),
+                "Lens implementations can only be derived from structs with named fields",
+            ));
+        };
 
     if fields.kind != FieldKind::Named {
         return Err(syn::Error::new(
-            input.span(),
+            fields_span,
             "Lens implementations can only be derived from structs with named fields",
         ));
     }
@@ -66,9 +67,10 @@ fn derive_struct(input: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, s
     // Define lens types for each field
     let defs = fields.iter().filter(|f| !f.attrs.ignore).map(|f| {
         let field_name = &f.ident.unwrap_named();
+        let doc = format!("Lens for the field `{}` on `{}`", field_name, ty);
 
         quote! {
-            /// Lens for the field on #ty
+            #[doc = #doc]
             #[allow(non_camel_case_types)]
             #[derive(Debug, Copy, Clone)]
             pub struct #field_name;
@@ -50,6 +50,19 @@ enum TypeParamForUserTraitAndLifetimeEnum<T: UserTrait + 'static> {
     V1(T),
 }
 
+#[derive(Clone, Data)]
+enum CStyleEnumWithoutPartialEq {
+    A,
+    B,
+}
+
+#[derive(Clone, Data)]
+enum GenericEnum<T> {
+    Empty,
+    One(T),
+    Two { first: T, second: T },
+}
+
 #[test]
 fn test_data_derive_same() {
     let plain = PlainStruct;
@@ -107,6 +120,25 @@ fn test_data_derive_same() {
         v.same(&TypeParamForUserTraitWithWhereClauseStruct { b: Value(6) })
     );
 
+    let a = CStyleEnumWithoutPartialEq::A;
+    assert!(a.same(&CStyleEnumWithoutPartialEq::A));
+    assert!(!a.same(&CStyleEnumWithoutPartialEq::B));
+
+    let one = GenericEnum::One(Value(1));
+    assert!(one.same(&GenericEnum::One(Value(1))));
+    assert!(!one.same(&GenericEnum::One(Value(2))));
+    assert!(!one.same(&GenericEnum::Empty));
+    let two = GenericEnum::Two {
+        first: Value(1),
+        second: Value(2),
+    };
+    assert!(two.same(&two));
+    assert!(!two.same(&GenericEnum::Two {
+        first: Value(1),
+        second: Value(3),
+    }));
+    assert!(GenericEnum::<Value>::Empty.same(&GenericEnum::Empty));
+
     let v = TypeParamForUserTraitAndLifetimeEnum::V1(Value(10));
     assert!(v.same(&v));
     assert_eq!(
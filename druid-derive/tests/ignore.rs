@@ -58,3 +58,24 @@ fn enums() {
     assert!(name_one.same(&name_two));
     assert!(tuple_one.same(&tuple_two));
 }
+
+#[test]
+fn everything_ignored() {
+    #[derive(Clone, Data)]
+    struct Cache {
+        #[data(ignore)]
+        hits: usize,
+    }
+
+    #[derive(Clone, Data)]
+    enum Lookup {
+        Cached(#[data(ignore)] usize),
+        Computed { value: usize },
+    }
+
+    let cache = Cache { hits: 1 };
+    assert!(cache.same(&Cache { hits: 2 }));
+    assert_eq!(cache.hits, 1);
+    assert!(Lookup::Cached(1).same(&Lookup::Cached(2)));
+    assert!(!Lookup::Cached(1).same(&Lookup::Computed { value: 1 }));
+}
@@ -70,3 +70,25 @@ fn enums() {
 fn same_sign(one: &f64, two: &f64) -> bool {
     one.signum() == two.signum()
 }
+
+#[test]
+fn module_path() {
+    #[derive(Clone, Data)]
+    struct Reading {
+        #[data(same_fn = "approx::eq")]
+        value: f64,
+    }
+
+    let one = Reading { value: 1.0 };
+    let two = Reading { value: 1.0 + 1e-12 };
+    let three = Reading { value: 1.1 };
+    assert!(one.same(&two));
+    assert!(!one.same(&three));
+}
+
+mod approx {
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn eq(one: &f64, two: &f64) -> bool {
+        (one - two).abs() < 1e-9
+    }
+}
@@ -116,14 +116,20 @@ impl Application {
     ///
     /// A globally active `Application` exists
     /// after [`new`] is called and until [`run`] returns.
+    /// Returns `None` if there is none, including when no `Application`
+    /// has been created at all.
     ///
     /// # Panics
     ///
-    /// Panics if called from a non-main thread.
+    /// Panics if called from a non-main thread while an `Application` exists.
     ///
     /// [`new`]: #method.new
     /// [`run`]: #method.run
     pub fn try_global() -> Option<Application> {
+        // no main thread means that there has never been an `Application`
+        if !util::main_thread_claimed() {
+            return None;
+        }
         util::assert_main_thread();
         GLOBAL_APP.with(|global_app| global_app.borrow().clone())
     }
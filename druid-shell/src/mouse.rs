@@ -249,6 +249,8 @@ pub enum Cursor {
     /// A vertical I-beam, for indicating insertion points in text.
     IBeam,
     Crosshair,
+    /// A pointing hand, for indicating a link or other clickable element.
+    Pointer,
     OpenHand,
     NotAllowed,
     ResizeLeftRight,
@@ -286,6 +288,7 @@ impl std::fmt::Debug for Cursor {
             Cursor::Arrow => write!(f, "Cursor::Arrow"),
             Cursor::IBeam => write!(f, "Cursor::IBeam"),
             Cursor::Crosshair => write!(f, "Cursor::Crosshair"),
+            Cursor::Pointer => write!(f, "Cursor::Pointer"),
             Cursor::OpenHand => write!(f, "Cursor::OpenHand"),
             Cursor::NotAllowed => write!(f, "Cursor::NotAllowed"),
             Cursor::ResizeLeftRight => write!(f, "Cursor::ResizeLeftRight"),
@@ -1141,6 +1141,7 @@ fn make_gdk_cursor(cursor: &Cursor, gdk_window: &gdk::Window) -> Option<gdk::Cur
                 Cursor::Arrow => "default",
                 Cursor::IBeam => "text",
                 Cursor::Crosshair => "crosshair",
+                Cursor::Pointer => "pointer",
                 Cursor::OpenHand => "grab",
                 Cursor::NotAllowed => "not-allowed",
                 Cursor::ResizeLeftRight => "ew-resize",
@@ -901,6 +901,7 @@ impl WindowHandle {
                 Cursor::Arrow => msg_send![nscursor, arrowCursor],
                 Cursor::IBeam => msg_send![nscursor, IBeamCursor],
                 Cursor::Crosshair => msg_send![nscursor, crosshairCursor],
+                Cursor::Pointer => msg_send![nscursor, pointingHandCursor],
                 Cursor::OpenHand => msg_send![nscursor, openHandCursor],
                 Cursor::NotAllowed => msg_send![nscursor, operationNotAllowedCursor],
                 Cursor::ResizeLeftRight => msg_send![nscursor, resizeLeftRightCursor],
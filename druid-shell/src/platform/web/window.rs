@@ -710,6 +710,7 @@ fn set_cursor(canvas: &web_sys::HtmlCanvasElement, cursor: &Cursor) {
                 Cursor::Arrow => "default",
                 Cursor::IBeam => "text",
                 Cursor::Crosshair => "crosshair",
+                Cursor::Pointer => "pointer",
                 Cursor::OpenHand => "grab",
                 Cursor::NotAllowed => "not-allowed",
                 Cursor::ResizeLeftRight => "ew-resize",
@@ -1520,6 +1520,7 @@ impl Cursor {
             Cursor::Arrow => IDC_ARROW,
             Cursor::IBeam => IDC_IBEAM,
             Cursor::Crosshair => IDC_CROSS,
+            Cursor::Pointer => IDC_HAND,
             Cursor::OpenHand => IDC_HAND,
             Cursor::NotAllowed => IDC_NO,
             Cursor::ResizeLeftRight => IDC_SIZEWE,
@@ -43,6 +43,11 @@ pub fn assert_main_thread() {
     }
 }
 
+/// Returns `true` if some thread has been registered as the main thread.
+pub fn main_thread_claimed() -> bool {
+    MAIN_THREAD_ID.load(Ordering::Acquire) != 0
+}
+
 /// Register the current thread as the main thread.
 ///
 /// # Panics
@@ -89,7 +89,8 @@ impl AppState {
         self.cursor = match self.cursor {
             Cursor::Arrow => Cursor::IBeam,
             Cursor::IBeam => Cursor::Crosshair,
-            Cursor::Crosshair => Cursor::OpenHand,
+            Cursor::Crosshair => Cursor::Pointer,
+            Cursor::Pointer => Cursor::OpenHand,
             Cursor::OpenHand => Cursor::NotAllowed,
             Cursor::NotAllowed => Cursor::ResizeLeftRight,
             Cursor::ResizeLeftRight => Cursor::ResizeUpDown,
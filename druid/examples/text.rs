@@ -124,6 +124,7 @@ fn build_root_widget() -> impl Widget<AppState> {
             ("Clip", LineBreaking::Clip),
             ("Wrap", LineBreaking::WordWrap),
             ("Overflow", LineBreaking::Overflow),
+            ("Ellipsis", LineBreaking::Ellipsis),
         ]))
         .lens(AppState::line_break_mode);
 
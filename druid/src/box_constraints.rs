@@ -34,7 +34,7 @@ use crate::kurbo::Size;
 /// [`layout`]: trait.Widget.html#tymethod.layout
 /// [Flutter BoxConstraints]: https://api.flutter.dev/flutter/rendering/BoxConstraints-class.html
 /// [rounded away from zero]: struct.Size.html#method.expand
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BoxConstraints {
     min: Size,
     max: Size,
@@ -370,6 +370,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn constrain() {
+        let bc = bc(10.0, 20.0, 100.0, 200.0);
+        // in range
+        assert_eq!(bc.constrain((50.0, 50.0)), Size::new(50.0, 50.0));
+        // below min and above max, on each axis
+        assert_eq!(bc.constrain((0.0, 300.0)), Size::new(10.0, 200.0));
+        assert_eq!(bc.constrain((500.0, 5.0)), Size::new(100.0, 20.0));
+        // fractional sizes are rounded away from zero before clamping
+        assert_eq!(bc.constrain((50.2, 99.5)), Size::new(51.0, 100.0));
+        assert_eq!(bc.constrain((99.9, 0.0)), Size::new(100.0, 20.0));
+        // unbounded constraints let anything finite through
+        let size = Size::new(1e6, 3.0);
+        assert_eq!(BoxConstraints::UNBOUNDED.constrain(size), size);
+        // tight constraints always win
+        let tight = BoxConstraints::tight(Size::new(30.0, 40.0));
+        assert_eq!(tight.constrain(Size::ZERO), Size::new(30.0, 40.0));
+    }
+
+    #[test]
+    fn shrink() {
+        let bc = bc(10.0, 20.0, 100.0, 200.0);
+        assert_eq!(bc.shrink((5.0, 5.0)), self::bc(5.0, 15.0, 95.0, 195.0));
+        // never shrinks below zero
+        assert_eq!(bc.shrink((50.0, 500.0)), self::bc(0.0, 0.0, 50.0, 0.0));
+        // the amount is rounded away from zero
+        assert_eq!(bc.shrink((0.5, 0.0)), self::bc(9.0, 20.0, 99.0, 200.0));
+        // unbounded stays unbounded
+        let shrunk = BoxConstraints::UNBOUNDED.shrink((10.0, 10.0));
+        assert!(!shrunk.is_width_bounded());
+        assert!(!shrunk.is_height_bounded());
+        assert_eq!(shrunk.min(), Size::ZERO);
+    }
+
+    #[test]
+    fn loosen() {
+        let loose = bc(10.0, 20.0, 100.0, 200.0).loosen();
+        assert_eq!(loose, bc(0.0, 0.0, 100.0, 200.0));
+        assert_eq!(loose.constrain(Size::ZERO), Size::ZERO);
+    }
+
     #[test]
     fn unbounded() {
         assert!(!BoxConstraints::UNBOUNDED.is_width_bounded());
@@ -20,6 +20,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use crate::kurbo::Vec2;
 use crate::{WidgetId, WindowId};
 
 /// The identity of a [`Selector`].
@@ -170,7 +171,7 @@ pub mod sys {
     use std::any::Any;
 
     use super::Selector;
-    use crate::{FileDialogOptions, FileInfo, SingleUse, WindowConfig};
+    use crate::{FileDialogOptions, FileInfo, Rect, SingleUse, WindowConfig};
 
     /// Quit the running application. This command is handled by the druid library.
     pub const QUIT_APP: Selector = Selector::new("druid-builtin.quit-app");
@@ -297,6 +298,19 @@ pub mod sys {
 
     /// Redo.
     pub const REDO: Selector = Selector::new("druid-builtin.menu-redo");
+
+    /// A [`Notification`] asking an ancestor to scroll, so that the given
+    /// region becomes visible.
+    ///
+    /// The payload is in the coordinate space of the widget receiving the
+    /// notification; it is translated as the notification moves up the tree.
+    /// This is handled by [`Scroll`]; you would normally send it with
+    /// [`EventCtx::scroll_to`].
+    ///
+    /// [`Notification`]: ../struct.Notification.html
+    /// [`Scroll`]: ../widget/struct.Scroll.html
+    /// [`EventCtx::scroll_to`]: ../struct.EventCtx.html#method.scroll_to
+    pub const SCROLL_TO_VIEW: Selector<Rect> = Selector::new("druid-builtin.scroll-to-view");
 }
 
 impl Selector<()> {
@@ -499,6 +513,18 @@ impl Notification {
     pub fn source(&self) -> WidgetId {
         self.source
     }
+
+    /// Move this notification into the coordinate space of the parent, for
+    /// notifications whose payload depends on it.
+    pub(crate) fn translated(self, offset: Vec2) -> Notification {
+        match self.get(sys::SCROLL_TO_VIEW) {
+            Some(rect) => Notification {
+                payload: Arc::new(*rect + offset),
+                ..self
+            },
+            None => self,
+        }
+    }
 }
 
 impl<T: Any> SingleUse<T> {
@@ -26,9 +26,9 @@ use crate::env::KeyLike;
 use crate::piet::{Piet, PietText, RenderContext};
 use crate::shell::Region;
 use crate::{
-    commands, Affine, Command, ContextMenu, Cursor, Env, ExtEventSink, Insets, MenuDesc,
-    Notification, Point, Rect, SingleUse, Size, Target, TimerToken, WidgetId, WindowDesc,
-    WindowHandle, WindowId,
+    commands, Affine, Application, Clipboard, Command, ContextMenu, Cursor, Env, ExtEventSink,
+    Insets, MenuDesc, Notification, Point, Rect, SingleUse, Size, Target, TimerToken, WidgetId,
+    WindowDesc, WindowHandle, WindowId,
 };
 
 /// A macro for implementing methods on multiple contexts.
@@ -54,6 +54,8 @@ pub(crate) struct ContextState<'a> {
     pub(crate) text: PietText,
     /// The id of the widget that currently has focus.
     pub(crate) focus_widget: Option<WidgetId>,
+    /// The payload of the drag in progress, if any.
+    pub(crate) drag_payload: &'a mut Option<Box<dyn Any>>,
     pub(crate) root_app_data_type: TypeId,
 }
 
@@ -107,6 +109,8 @@ pub struct LayoutCtx<'a, 'b> {
     pub(crate) state: &'a mut ContextState<'b>,
     pub(crate) widget_state: &'a mut WidgetState,
     pub(crate) mouse_pos: Option<Point>,
+    /// Lay out every descendant, even if it could use its cached size.
+    pub(crate) skip_cache: bool,
 }
 
 /// Z-order paint operations with transformations.
@@ -276,7 +280,7 @@ impl_context_method!(EventCtx<'_, '_>, UpdateCtx<'_, '_>, {
     /// preferences of a child widget. (If that isn't what you want, use [`set_cursor`] instead.)
     ///
     /// [`clear_cursor`]: EventCtx::clear_cursor
-    /// [`set_cursor`]: EventCtx::override_cursor
+    /// [`set_cursor`]: EventCtx::set_cursor
     /// [`hot`]: EventCtx::is_hot
     /// [`active`]: EventCtx::is_active
     pub fn override_cursor(&mut self, cursor: &Cursor) {
@@ -331,6 +335,17 @@ impl_context_method!(EventCtx<'_, '_>, UpdateCtx<'_, '_>, LifeCycleCtx<'_, '_>,
     }
 
     /// Request an animation frame.
+    ///
+    /// This will cause an [`Event::AnimFrame`] to be delivered to this widget
+    /// right before the next frame is painted. The event carries the time
+    /// in nanoseconds since the previous animation frame, which can be used
+    /// to advance the animation smoothly.
+    ///
+    /// Only one frame is delivered per request; a widget that is animating
+    /// should call this again while handling the `AnimFrame` event, until the
+    /// animation is done.
+    ///
+    /// [`Event::AnimFrame`]: enum.Event.html#variant.AnimFrame
     pub fn request_anim_frame(&mut self) {
         self.widget_state.request_anim = true;
     }
@@ -385,7 +400,15 @@ impl_context_method!(
         /// Request a timer event.
         ///
         /// The return value is a token, which can be used to associate the
-        /// request with the event.
+        /// request with the event: after `deadline` has passed, this widget
+        /// receives an [`Event::Timer`] with the same token. A widget can have
+        /// several outstanding timers, and should check the token to tell them
+        /// apart.
+        ///
+        /// Timers cannot be cancelled; to ignore a timer, forget its token.
+        /// A timer that fires after its widget has been removed is dropped.
+        ///
+        /// [`Event::Timer`]: enum.Event.html#variant.Timer
         pub fn request_timer(&mut self, deadline: Duration) -> TimerToken {
             self.state.request_timer(&mut self.widget_state, deadline)
         }
@@ -421,12 +444,31 @@ impl EventCtx<'_, '_> {
         self.notifications.push_back(note);
     }
 
+    /// Ask the nearest scrolling ancestor, such as a [`Scroll`], to scroll
+    /// so that `rect` becomes visible.
+    ///
+    /// `rect` is in this widget's coordinate space; pass
+    /// `ctx.size().to_rect()` to make the whole widget visible.
+    ///
+    /// This sends a [`SCROLL_TO_VIEW`] notification.
+    ///
+    /// [`Scroll`]: widget/struct.Scroll.html
+    /// [`SCROLL_TO_VIEW`]: commands/constant.SCROLL_TO_VIEW.html
+    pub fn scroll_to(&mut self, rect: Rect) {
+        self.submit_notification(crate::commands::SCROLL_TO_VIEW.with(rect));
+    }
+
     /// Set the "active" state of the widget.
     ///
+    /// While a widget is active it captures the mouse: every `MouseMove`,
+    /// `MouseUp` and `Wheel` event is routed to it, wherever the pointer
+    /// is, until it calls `set_active(false)`. This is what lets a drag
+    /// continue when the pointer leaves the widget. On desktop platforms this
+    /// also works outside the window, as long as a mouse button is held.
+    ///
     /// See [`EventCtx::is_active`](struct.EventCtx.html#method.is_active).
     pub fn set_active(&mut self, active: bool) {
         self.widget_state.is_active = active;
-        // TODO: plumb mouse grab through to platform (through druid-shell)
     }
 
     /// Create a new window.
@@ -531,6 +573,19 @@ impl EventCtx<'_, '_> {
         }
     }
 
+    /// A handle to the system clipboard, or `None` if there is no running
+    /// [`Application`], as in unit tests.
+    ///
+    /// A copy is usually done by writing with [`Clipboard::put_string`], and
+    /// a paste by reading with [`Clipboard::get_string`].
+    ///
+    /// [`Clipboard::put_string`]: struct.Clipboard.html#method.put_string
+    /// [`Clipboard::get_string`]: struct.Clipboard.html#method.get_string
+    /// [`Application`]: struct.Application.html
+    pub fn clipboard(&self) -> Option<Clipboard> {
+        Application::try_global().map(|app| app.clipboard())
+    }
+
     /// Give up focus.
     ///
     /// This should only be called by a widget that currently has focus.
@@ -549,19 +604,70 @@ impl EventCtx<'_, '_> {
         }
     }
 
+    /// Start dragging `payload`.
+    ///
+    /// This is usually called on a `MouseMove`, after the mouse was pressed
+    /// on this widget and has moved far enough. Until the mouse is released,
+    /// every widget in the window can inspect the payload with
+    /// [`drag_payload`]; the `MouseUp` that ends the drag is still delivered
+    /// with the payload in place, so that the widget under the mouse can
+    /// accept the drop.
+    ///
+    /// Starting a new drag replaces the one in progress, if any. See
+    /// [`WidgetExt::draggable`] and [`WidgetExt::drop_target`] for an
+    /// implementation of the common case.
+    ///
+    /// [`drag_payload`]: #method.drag_payload
+    /// [`WidgetExt::draggable`]: widget/trait.WidgetExt.html#method.draggable
+    /// [`WidgetExt::drop_target`]: widget/trait.WidgetExt.html#method.drop_target
+    pub fn start_drag(&mut self, payload: impl Any) {
+        *self.state.drag_payload = Some(Box::new(payload));
+    }
+
+    /// The payload of the drag in progress, if there is one and it is a `P`.
+    pub fn drag_payload<P: Any>(&self) -> Option<&P> {
+        self.state
+            .drag_payload
+            .as_ref()
+            .and_then(|payload| payload.downcast_ref())
+    }
+
+    /// Returns `true` if a drag is in progress, whatever its payload.
+    pub fn is_dragging(&self) -> bool {
+        self.state.drag_payload.is_some()
+    }
+
+    /// Stop the drag in progress, if any, without dropping it anywhere.
+    pub fn cancel_drag(&mut self) {
+        *self.state.drag_payload = None;
+    }
+}
+
+impl_context_method!(EventCtx<'_, '_>, LifeCycleCtx<'_, '_>, {
     /// Request an update cycle.
     ///
     /// After this, `update` will be called on the widget in the next update cycle, even
     /// if there's not a data change.
     ///
     /// The use case for this method is when a container widget synthesizes data for its
-    /// children. This is appropriate in specialized cases, but before reaching for this
-    /// method, consider whether it might be better to refactor to be more idiomatic, in
-    /// particular to make that data available in the app state.
+    /// children, or when a widget keeps some internal state (like hover or animation
+    /// progress) that its `update` uses to work out what changed. This is appropriate in
+    /// specialized cases, but before reaching for this method, consider whether it might
+    /// be better to refactor to be more idiomatic, in particular to make that data
+    /// available in the app state.
+    ///
+    /// Requesting an update does not invalidate anything by itself: if the widget only
+    /// needs to be repainted or laid out again, call [`request_paint`] or
+    /// [`request_layout`] directly. Those can also be called from `update`, so a widget
+    /// that recomputes derived state there can decide what to invalidate once it knows
+    /// what changed.
+    ///
+    /// [`request_paint`]: #method.request_paint
+    /// [`request_layout`]: #method.request_layout
     pub fn request_update(&mut self) {
         self.widget_state.request_update = true;
     }
-}
+});
 
 impl UpdateCtx<'_, '_> {
     /// Returns `true` if this widget or a descendent as explicitly requested
@@ -672,6 +778,13 @@ impl PaintCtx<'_, '_, '_> {
     }
 
     /// Returns the region that needs to be repainted.
+    ///
+    /// This is in the widget's own coordinate space. Children whose paint rect
+    /// doesn't intersect it are skipped by [`WidgetPod::paint`]; widgets that
+    /// draw a lot of content can use it to skip drawing the parts that are
+    /// unchanged.
+    ///
+    /// [`WidgetPod::paint`]: struct.WidgetPod.html#method.paint
     #[inline]
     pub fn region(&self) -> &Region {
         &self.region
@@ -753,6 +866,7 @@ impl<'a> ContextState<'a> {
         window: &'a WindowHandle,
         window_id: WindowId,
         focus_widget: Option<WidgetId>,
+        drag_payload: &'a mut Option<Box<dyn Any>>,
     ) -> Self {
         ContextState {
             command_queue,
@@ -760,6 +874,7 @@ impl<'a> ContextState<'a> {
             window,
             window_id,
             focus_widget,
+            drag_payload,
             text: window.text(),
             root_app_data_type: TypeId::of::<T>(),
         }
@@ -49,6 +49,12 @@ pub struct WidgetPod<T, W> {
     old_data: Option<T>,
     env: Option<Env>,
     inner: W,
+    // the constraints from the last layout pass; `None` if the cached size
+    // can't be trusted, e.g. because the widget has been updated since.
+    layout_bc: Option<BoxConstraints>,
+    // whether the last call to `layout` returned the cached size, without
+    // laying out the subtree.
+    layout_cached: bool,
     // stashed layout so we don't recompute this when debugging
     debug_widget_text: TextLayout<ArcStr>,
 }
@@ -172,6 +178,8 @@ impl<T, W: Widget<T>> WidgetPod<T, W> {
             old_data: None,
             env: None,
             inner,
+            layout_bc: None,
+            layout_cached: false,
             debug_widget_text: TextLayout::new(),
         }
     }
@@ -225,8 +233,11 @@ impl<T, W: Widget<T>> WidgetPod<T, W> {
     /// Set the layout [`Rect`].
     ///
     /// This is soft-deprecated; you should use [`set_origin`] instead for new code.
+    /// The size of the rect should be the size returned by [`layout`]; only its
+    /// origin is used.
     ///
     /// [`set_origin`]: WidgetPod::set_origin
+    /// [`layout`]: WidgetPod::layout
     pub fn set_layout_rect(&mut self, ctx: &mut LayoutCtx, data: &T, env: &Env, layout_rect: Rect) {
         if layout_rect.size() != self.state.size {
             log::warn!("set_layout_rect passed different size than returned by layout method");
@@ -247,10 +258,23 @@ impl<T, W: Widget<T>> WidgetPod<T, W> {
     /// [`Size`]: struct.Size.html
     /// [`LifeCycle::Size`]: enum.LifeCycle.html#variant.Size
     pub fn set_origin(&mut self, ctx: &mut LayoutCtx, data: &T, env: &Env, origin: Point) {
+        let moved = origin != self.state.origin;
         self.state.origin = origin;
         self.state.is_expecting_set_origin_call = false;
         let layout_rect = self.layout_rect();
 
+        // if we skipped laying out our descendants, their hot state is still
+        // based on where we were. If the mouse is, or was, over us, lay them
+        // out again so that they see where we are now.
+        let is_under_mouse = ctx
+            .mouse_pos
+            .map_or(false, |pos| layout_rect.winding(pos) != 0);
+        if moved && self.layout_cached && (self.state.is_hot || is_under_mouse) {
+            if let Some(bc) = self.layout_bc {
+                self.layout_inner(ctx, &bc, data, env, true);
+            }
+        }
+
         // if the widget has moved, it may have moved under the mouse, in which
         // case we need to handle that.
         if WidgetPod::set_hot_state(
@@ -353,6 +377,63 @@ impl<T, W: Widget<T>> WidgetPod<T, W> {
         self.state.baseline_offset
     }
 
+    /// Lay out the widget, without looking at the cached size.
+    ///
+    /// If `skip_cache` is set, none of the descendants use their cached
+    /// size either.
+    fn layout_inner(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &T,
+        env: &Env,
+        skip_cache: bool,
+    ) -> Size {
+        self.state.needs_layout = false;
+        self.layout_bc = Some(*bc);
+        self.layout_cached = false;
+
+        let child_mouse_pos = match ctx.mouse_pos {
+            Some(pos) => Some(pos - self.layout_rect().origin().to_vec2()),
+            None => None,
+        };
+        let prev_size = self.state.size;
+
+        let mut child_ctx = LayoutCtx {
+            widget_state: &mut self.state,
+            state: ctx.state,
+            mouse_pos: child_mouse_pos,
+            skip_cache: ctx.skip_cache || skip_cache,
+        };
+
+        let new_size = self.inner.layout(&mut child_ctx, bc, data, env);
+        if new_size != prev_size {
+            let mut child_ctx = LifeCycleCtx {
+                widget_state: child_ctx.widget_state,
+                state: child_ctx.state,
+            };
+            let size_event = LifeCycle::Size(new_size);
+            self.inner.lifecycle(&mut child_ctx, &size_event, data, env);
+        }
+
+        ctx.widget_state.merge_up(&mut child_ctx.widget_state);
+        self.state.size = new_size;
+        self.log_layout_issues(new_size);
+
+        new_size
+    }
+
+    fn log_layout_issues(&self, size: Size) {
+        if size.width.is_infinite() {
+            let name = self.widget().type_name();
+            log::warn!("Widget `{}` has an infinite width.", name);
+        }
+        if size.height.is_infinite() {
+            let name = self.widget().type_name();
+            log::warn!("Widget `{}` has an infinite height.", name);
+        }
+    }
+
     /// Determines if the provided `mouse_pos` is inside `rect`
     /// and if so updates the hot state and sends `LifeCycle::HotChanged`.
     ///
@@ -517,7 +598,17 @@ impl<T: Data, W: Widget<T>> WidgetPod<T, W> {
     /// Generally called by container widgets as part of their [`layout`]
     /// method.
     ///
+    /// If the widget has not requested layout, has not been updated since the
+    /// last layout pass, and the constraints are the same as last time, the
+    /// widget's [`layout`] method is skipped and the previous size is returned.
+    /// If [`set_origin`] then moves the widget to or from under the mouse, it
+    /// is laid out after all, so that its descendants update their hot state.
+    /// Widgets whose layout depends on state that changes outside of `update`
+    /// must call [`request_layout`] when that state changes.
+    ///
     /// [`layout`]: trait.Widget.html#tymethod.layout
+    /// [`request_layout`]: struct.EventCtx.html#method.request_layout
+    /// [`set_origin`]: #method.set_origin
     pub fn layout(
         &mut self,
         ctx: &mut LayoutCtx,
@@ -533,47 +624,12 @@ impl<T: Data, W: Widget<T>> WidgetPod<T, W> {
             return Size::ZERO;
         }
 
-        self.state.needs_layout = false;
         self.state.is_expecting_set_origin_call = true;
-
-        let child_mouse_pos = match ctx.mouse_pos {
-            Some(pos) => Some(pos - self.layout_rect().origin().to_vec2()),
-            None => None,
-        };
-        let prev_size = self.state.size;
-
-        let mut child_ctx = LayoutCtx {
-            widget_state: &mut self.state,
-            state: ctx.state,
-            mouse_pos: child_mouse_pos,
-        };
-
-        let new_size = self.inner.layout(&mut child_ctx, bc, data, env);
-        if new_size != prev_size {
-            let mut child_ctx = LifeCycleCtx {
-                widget_state: child_ctx.widget_state,
-                state: child_ctx.state,
-            };
-            let size_event = LifeCycle::Size(new_size);
-            self.inner.lifecycle(&mut child_ctx, &size_event, data, env);
-        }
-
-        ctx.widget_state.merge_up(&mut child_ctx.widget_state);
-        self.state.size = new_size;
-        self.log_layout_issues(new_size);
-
-        new_size
-    }
-
-    fn log_layout_issues(&self, size: Size) {
-        if size.width.is_infinite() {
-            let name = self.widget().type_name();
-            log::warn!("Widget `{}` has an infinite width.", name);
-        }
-        if size.height.is_infinite() {
-            let name = self.widget().type_name();
-            log::warn!("Widget `{}` has an infinite height.", name);
+        if !self.state.needs_layout && !ctx.skip_cache && self.layout_bc == Some(*bc) {
+            self.layout_cached = true;
+            return self.state.size;
         }
+        self.layout_inner(ctx, bc, data, env, false)
     }
 
     /// Execute the closure with this widgets `EventCtx`.
@@ -614,7 +670,7 @@ impl<T: Data, W: Widget<T>> WidgetPod<T, W> {
         if self.state.is_expecting_set_origin_call && !event.should_propagate_to_hidden() {
             log::warn!(
                 "{:?} received an event ({:?}) without having been laid out. \
-                This likely indicates a missed call to set_layout_rect.",
+                This likely indicates a missed call to set_origin.",
                 ctx.widget_id(),
                 event,
             );
@@ -809,6 +865,8 @@ impl<T: Data, W: Widget<T>> WidgetPod<T, W> {
         data: &mut T,
         env: &Env,
     ) {
+        // unhandled notifications continue in our parent's coordinate space
+        let offset = self.state.layout_rect().origin().to_vec2() - self.state.viewport_offset;
         let EventCtx {
             state,
             notifications: parent_notifications,
@@ -831,7 +889,7 @@ impl<T: Data, W: Widget<T>> WidgetPod<T, W> {
                 inner_ctx.is_handled = false;
             } else if let Event::Notification(notification) = event {
                 // we will try again with the next parent
-                parent_notifications.push_back(notification);
+                parent_notifications.push_back(notification.translated(offset));
             } else {
                 unreachable!()
             }
@@ -974,7 +1032,16 @@ impl<T: Data, W: Widget<T>> WidgetPod<T, W> {
     /// Generally called by container widgets as part of their [`update`]
     /// method.
     ///
+    /// The child's `update` is skipped if neither the data nor the [`Env`]
+    /// has changed since the last call, as determined by [`Data::same`],
+    /// unless the child (or one of its descendants) called
+    /// [`request_update`]. Containers can call this for every child without
+    /// worrying about the cost of unchanged subtrees.
+    ///
     /// [`update`]: trait.Widget.html#tymethod.update
+    /// [`Env`]: struct.Env.html
+    /// [`Data::same`]: trait.Data.html#tymethod.same
+    /// [`request_update`]: struct.EventCtx.html#method.request_update
     pub fn update(&mut self, ctx: &mut UpdateCtx, data: &T, env: &Env) {
         if !self.state.request_update {
             match (self.old_data.as_ref(), self.env.as_ref()) {
@@ -1004,6 +1071,7 @@ impl<T: Data, W: Widget<T>> WidgetPod<T, W> {
             .update(&mut child_ctx, self.old_data.as_ref().unwrap(), data, env);
         self.old_data = Some(data.clone());
         self.env = Some(env.clone());
+        self.layout_bc = None;
 
         self.state.request_update = false;
         ctx.widget_state.merge_up(&mut self.state);
@@ -1179,12 +1247,14 @@ mod tests {
         let window = WindowHandle::default();
         let ext_host = ExtEventHost::default();
         let ext_handle = ext_host.make_sink();
+        let mut drag_payload = None;
         let mut state = ContextState::new::<Option<u32>>(
             &mut command_queue,
             &ext_handle,
             &window,
             WindowId::next(),
             None,
+            &mut drag_payload,
         );
 
         let mut ctx = LifeCycleCtx {
@@ -65,7 +65,22 @@ pub use druid_derive::Data;
 /// custom function that will be used instead.
 ///
 /// This function must have a signature in the form, `fn<T>(&T, &T) -> bool`,
-/// where `T` is the type of the field.
+/// where `T` is the type of the field. The path is resolved from the module
+/// where the type is defined, and the compiler will point at the attribute
+/// if it can't be found. A field can't be both `ignore`d and given a `same_fn`.
+///
+/// ## Standard library types
+///
+/// `Data` is implemented for a number of common types from `std`:
+///
+/// - integers, `bool`, `char` and `String` compare with `==`.
+/// - `f32` and `f64` compare their bits, so `NaN` is the same as itself,
+///   but `0.0` and `-0.0` are not the same.
+/// - `&'static str` compares the pointers, not the contents.
+/// - `Arc<T>` and `Rc<T>` compare by pointer identity, without looking at the
+///   contents. This makes them a cheap way to include large, immutable
+///   values in your data: to change the value, replace the `Arc`.
+/// - `Option`, `Result`, tuples and arrays compare their contents with `same`.
 ///
 /// ## Collection types
 ///
@@ -100,7 +115,7 @@ pub use druid_derive::Data;
 ///
 /// In the case of a "c-style" enum (one that only contains unit variants,
 /// that is where no variant has fields), the implementation that is generated
-/// checks for equality. Therefore, such types must also implement `PartialEq`.
+/// only checks that both values are the same variant.
 ///
 /// [`Data::same`]: trait.Data.html#tymethod.same
 /// [`im` crate]: https://docs.rs/im
@@ -163,30 +178,37 @@ impl_data_simple!(std::num::NonZeroUsize);
 //TODO: remove me!?
 impl_data_simple!(String);
 
+/// Compares the pointers, and not the contents.
 impl Data for &'static str {
     fn same(&self, other: &Self) -> bool {
         ptr::eq(*self, *other)
     }
 }
 
+/// Compares the bit representation, so that `NaN` is the same as itself.
 impl Data for f32 {
     fn same(&self, other: &Self) -> bool {
         self.to_bits() == other.to_bits()
     }
 }
 
+/// Compares the bit representation, so that `NaN` is the same as itself.
 impl Data for f64 {
     fn same(&self, other: &Self) -> bool {
         self.to_bits() == other.to_bits()
     }
 }
 
+/// Compares by pointer identity; two separately allocated `Arc`s are never
+/// the same, even if their contents are equal.
 impl<T: ?Sized + 'static> Data for Arc<T> {
     fn same(&self, other: &Self) -> bool {
         Arc::ptr_eq(self, other)
     }
 }
 
+/// Compares by pointer identity; two separately allocated `Rc`s are never
+/// the same, even if their contents are equal.
 impl<T: ?Sized + 'static> Data for Rc<T> {
     fn same(&self, other: &Self) -> bool {
         Rc::ptr_eq(self, other)
@@ -520,6 +542,27 @@ mod test {
         assert!(!one.same(&two));
     }
 
+    #[test]
+    fn floats() {
+        assert!(f64::NAN.same(&f64::NAN));
+        assert!(!0.0f64.same(&-0.0));
+        assert!(1.5f32.same(&1.5));
+    }
+
+    #[test]
+    fn pointer_identity() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let one = Arc::new(vec![1, 2, 3]);
+        assert!(one.same(&one.clone()));
+        assert!(!one.same(&Arc::new(vec![1, 2, 3])));
+
+        let one = Rc::new(String::from("one"));
+        assert!(one.same(&one.clone()));
+        assert!(!one.same(&Rc::new(String::from("one"))));
+    }
+
     #[test]
     fn static_strings() {
         let first = "test";
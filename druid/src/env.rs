@@ -150,7 +150,7 @@ pub trait KeyLike<T> {
 
 impl<T: ValueType> KeyLike<T> for Key<T> {
     fn changed(&self, old: &Env, new: &Env) -> bool {
-        !old.get_untyped(self).same(new.get_untyped(self))
+        key_changed(self, old, new)
     }
 }
 
@@ -158,11 +158,21 @@ impl<T> KeyLike<T> for KeyOrValue<T> {
     fn changed(&self, old: &Env, new: &Env) -> bool {
         match self {
             KeyOrValue::Concrete(_) => false,
-            KeyOrValue::Key(key) => !old.get_untyped(key).same(new.get_untyped(key)),
+            KeyOrValue::Key(key) => key_changed(key, old, new),
         }
     }
 }
 
+/// A key that is added or removed counts as changed; a key that is missing
+/// from both environments does not.
+fn key_changed<T>(key: &Key<T>, old: &Env, new: &Env) -> bool {
+    match (old.try_get_untyped(key), new.try_get_untyped(key)) {
+        (Ok(old), Ok(new)) => !old.same(new),
+        (Err(_), Err(_)) => false,
+        _ => true,
+    }
+}
+
 /// Values which can be stored in an environment.
 pub trait ValueType: Sized + Clone + Into<Value> {
     /// Attempt to convert the generic `Value` into this type.
@@ -194,10 +204,22 @@ impl Env {
     /// State for whether or not to paint colorful rectangles for layout
     /// debugging.
     ///
-    /// Set by the `debug_paint_layout()` method on [`WidgetExt`]'.
+    /// Set by the `debug_paint_layout()` method on [`WidgetExt`]. To see the
+    /// layout of every widget in the application, you can also set it when
+    /// configuring the `Env`:
+    ///
+    /// ```no_run
+    /// # use druid::widget::Label;
+    /// # use druid::{AppLauncher, Env, WindowDesc};
+    /// # let window = WindowDesc::new(|| Label::new("Hello"));
+    /// AppLauncher::with_window(window)
+    ///     .configure_env(|env, _| env.set(Env::DEBUG_PAINT, true))
+    ///     .launch(())
+    ///     .expect("launch failed");
+    /// ```
     ///
     /// [`WidgetExt`]: trait.WidgetExt.html
-    pub(crate) const DEBUG_PAINT: Key<bool> = Key::new("org.linebender.druid.built-in.debug-paint");
+    pub const DEBUG_PAINT: Key<bool> = Key::new("org.linebender.druid.built-in.debug-paint");
 
     /// State for whether or not to paint `WidgetId`s, for event debugging.
     ///
@@ -246,21 +268,35 @@ impl Env {
         }
     }
 
-    /// Trys to get a value from the environment.
+    /// Tries to get a value from the environment.
     ///
     /// If the value is not found, the raw key is returned as the error.
     ///
+    /// This is useful for widgets that want to fall back to a default when
+    /// a theme doesn't provide some key, instead of panicking:
+    ///
+    /// ```
+    /// use druid::{Env, Key};
+    ///
+    /// const CORNER_RADIUS: Key<f64> = Key::new("org.example.corner-radius");
+    ///
+    /// fn corner_radius(env: &Env) -> f64 {
+    ///     env.try_get(CORNER_RADIUS).unwrap_or(4.0)
+    /// }
+    /// ```
+    ///
     /// # Panics
     ///
     /// Panics if the value for the key is found, but has the wrong type.
     pub fn try_get<V: ValueType>(&self, key: impl Borrow<Key<V>>) -> Result<V, MissingKeyError> {
-        self.0
-            .map
-            .get(key.borrow().key)
-            .map(|value| value.to_inner_unchecked())
-            .ok_or(MissingKeyError {
-                key: key.borrow().key.into(),
-            })
+        let key = key.borrow().key;
+        match self.0.map.get(key) {
+            Some(value) => match V::try_from_value(value) {
+                Ok(value) => Ok(value),
+                Err(err) => panic!("Invalid type for key '{}': {}", key, err),
+            },
+            None => Err(MissingKeyError { key: key.into() }),
+        }
     }
 
     /// Gets a value from the environment, in its encapsulated [`Value`] form,
@@ -402,7 +438,7 @@ impl Value {
         use Value::*;
         matches!(
             (self, other),
-            (Point(_) , Point(_))
+            (Point(_), Point(_))
                 | (Size(_), Size(_))
                 | (Rect(_), Rect(_))
                 | (Insets(_), Insets(_))
@@ -600,6 +636,32 @@ mod tests {
         assert_eq!(key.resolve(&env), value.resolve(&env));
     }
 
+    #[test]
+    #[should_panic(expected = "org.linebender.test.my-mistyped-key")]
+    fn mistyped_key_names_key() {
+        const AS_F64: Key<f64> = Key::new("org.linebender.test.my-mistyped-key");
+        const AS_COLOR: Key<Color> = Key::new("org.linebender.test.my-mistyped-key");
+        let env = Env::default().adding(AS_F64, 1.0);
+        env.get(AS_COLOR);
+    }
+
+    #[test]
+    fn missing_key_is_recoverable() {
+        const MY_KEY: Key<f64> = Key::new("org.linebender.test.my-missing-key");
+        let empty = Env::default();
+        let env = Env::default().adding(MY_KEY, 1.0);
+
+        assert_eq!(
+            empty.try_get(MY_KEY).unwrap_err().raw_key(),
+            "org.linebender.test.my-missing-key"
+        );
+        assert_eq!(env.try_get(MY_KEY).ok(), Some(1.0));
+
+        assert!(!MY_KEY.changed(&empty, &empty));
+        assert!(MY_KEY.changed(&empty, &env));
+        assert!(MY_KEY.changed(&env, &empty));
+    }
+
     #[test]
     fn key_is_send_and_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
@@ -228,6 +228,14 @@ pub enum LifeCycle {
     /// Widgets which wish to participate in automatic focus (using tab to change
     /// focus) must handle this event and call [`LifeCycleCtx::register_for_focus`].
     ///
+    /// ## Removal
+    ///
+    /// There is no matching event when a widget leaves the tree; its parent
+    /// simply drops it. Anything a widget starts from here, such as an
+    /// animation or a timer, stops with it: a dropped widget gets no more
+    /// animation frames, and its pending timers are discarded. Any other
+    /// cleanup belongs in a `Drop` impl.
+    ///
     /// [`LifeCycleCtx::register_child`]: struct.LifeCycleCtx.html#method.register_child
     /// [`WidgetPod`]: struct.WidgetPod.html
     /// [`LifeCycleCtx::register_for_focus`]: struct.LifeCycleCtx.html#method.register_for_focus
@@ -78,6 +78,18 @@ pub trait LensExt<A: ?Sized, B: ?Sized>: Lens<A, B> {
     /// let lens = lens!(Foo, x).then(lens!((u32, bool), 1));
     /// assert_eq!(lens.get(&Foo { x: (0, true) }), true);
     /// ```
+    ///
+    /// Writes through the composed lens reach the innermost value:
+    ///
+    /// ```
+    /// # use druid::*;
+    /// struct Inner { name: String }
+    /// struct Outer { inner: Inner }
+    /// let lens = lens!(Outer, inner).then(lens!(Inner, name));
+    /// let mut data = Outer { inner: Inner { name: "before".into() } };
+    /// lens.with_mut(&mut data, |name: &mut String| name.push_str("!"));
+    /// assert_eq!(data.inner.name, "before!");
+    /// ```
     fn then<Other, C>(self, other: Other) -> Then<Self, Other, B>
     where
         Other: Lens<B, C> + Sized,
@@ -176,6 +188,14 @@ pub trait LensExt<A: ?Sized, B: ?Sized>: Lens<A, B> {
     /// # use druid::*;
     /// assert_eq!(lens::Identity.index(2).get(&vec![0u32, 1, 2, 3]), 2);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Like indexing the container directly, using this lens panics if the
+    /// index is out of bounds. See [`CheckedIndex`] for a lens that tolerates
+    /// missing indices.
+    ///
+    /// [`CheckedIndex`]: struct.CheckedIndex.html
     fn index<I>(self, index: I) -> Then<Self, Index<I>, B>
     where
         I: Clone,
@@ -287,13 +307,31 @@ where
 /// let lens = druid::lens!((u32, bool), 1);
 /// let lens = druid::lens!([u8], [4]);
 /// ```
+///
+/// A path of fields separated by `.` focuses on a nested field, which saves
+/// chaining one lens per level. Named fields and tuple indices can be mixed:
+///
+/// ```
+/// # use druid::*;
+/// struct Inner { pair: (String, f64) }
+/// struct Outer { inner: Inner }
+/// let lens = lens!(Outer, inner.pair.1);
+/// let mut data = Outer { inner: Inner { pair: ("a".into(), 2.0) } };
+/// lens.put(&mut data, 3.0);
+/// assert_eq!(lens.get(&data), 3.0);
+/// ```
+///
+/// The result is an ordinary lens, so it can be used with
+/// [`WidgetExt::lens`] next to the lenses generated by `#[derive(Lens)]`.
+///
+/// [`WidgetExt::lens`]: widget/trait.WidgetExt.html#method.lens
 #[macro_export]
 macro_rules! lens {
     ($ty:ty, [$index:expr]) => {
         $crate::lens::Field::new::<$ty, _>(move |x| &x[$index], move |x| &mut x[$index])
     };
-    ($ty:ty, $field:tt) => {
-        $crate::lens::Field::new::<$ty, _>(move |x| &x.$field, move |x| &mut x.$field)
+    ($ty:ty, $($field:tt).+) => {
+        $crate::lens::Field::new::<$ty, _>(move |x| &x$(.$field)+, move |x| &mut x$(.$field)+)
     };
 }
 
@@ -339,7 +377,7 @@ where
     }
 }
 
-impl<T: Clone, U: Clone, B> Clone for Then<T, U, B> {
+impl<T: Clone, U: Clone, B: ?Sized> Clone for Then<T, U, B> {
     fn clone(&self) -> Self {
         Self {
             left: self.left.clone(),
@@ -350,6 +388,39 @@ impl<T: Clone, U: Clone, B> Clone for Then<T, U, B> {
 }
 
 /// `Lens` built from a getter and a setter
+///
+/// This is useful for exposing a value that is computed from the data,
+/// rather than stored in it. The getter produces the value, and the setter
+/// writes an edited value back.
+///
+/// For instance, here is a lens that shows an `f64` with two decimals, and
+/// that keeps the old value if the edited text isn't a number:
+///
+/// ```
+/// # use druid::*;
+/// let lens = lens::Map::new(
+///     |x: &f64| format!("{:.2}", x),
+///     |x: &mut f64, s: String| {
+///         if let Ok(value) = s.parse() {
+///             *x = value;
+///         }
+///     },
+/// );
+/// let mut data = 1.0 / 3.0;
+/// assert_eq!(lens.get(&data), "0.33");
+/// lens.put(&mut data, "2.5".to_string());
+/// assert_eq!(data, 2.5);
+/// lens.put(&mut data, "not a number".to_string());
+/// assert_eq!(data, 2.5);
+/// ```
+///
+/// Note that the setter is called after every call to [`with_mut`], even if
+/// the value was not changed.
+///
+/// See also [`LensExt::map`].
+///
+/// [`with_mut`]: trait.Lens.html#tymethod.with_mut
+/// [`LensExt::map`]: trait.LensExt.html#method.map
 #[derive(Debug, Copy, Clone)]
 pub struct Map<Get, Put> {
     get: Get,
@@ -386,6 +457,68 @@ where
     }
 }
 
+/// `Lens` that shows a value as a `String`, and parses it back on write.
+///
+/// This is a bidirectional alternative to combining [`Map`] with the
+/// [`Parse`] widget, where both directions of the conversion are under your
+/// control. If the written string can't be parsed, the value is left as it
+/// was.
+///
+/// ```
+/// # use druid::*;
+/// let lens = lens::Formatter::new(
+///     |value: &f64| format!("{:.1}", value),
+///     |input: &str| input.trim().parse().ok(),
+/// );
+/// let mut data = 2.0;
+/// assert_eq!(lens.get(&data), "2.0");
+/// lens.put(&mut data, " 3.5 ".to_string());
+/// assert_eq!(data, 3.5);
+/// lens.put(&mut data, "three".to_string());
+/// assert_eq!(data, 3.5);
+/// ```
+///
+/// [`Map`]: struct.Map.html
+/// [`Parse`]: ../widget/struct.Parse.html
+#[derive(Debug, Copy, Clone)]
+pub struct Formatter<Format, Parse> {
+    format: Format,
+    parse: Parse,
+}
+
+impl<Format, Parse> Formatter<Format, Parse> {
+    /// Construct a lens from a `format` and a `parse` function.
+    pub fn new<T>(format: Format, parse: Parse) -> Self
+    where
+        Format: Fn(&T) -> String,
+        Parse: Fn(&str) -> Option<T>,
+    {
+        Self { format, parse }
+    }
+}
+
+impl<T, Format, Parse> Lens<T, String> for Formatter<Format, Parse>
+where
+    Format: Fn(&T) -> String,
+    Parse: Fn(&str) -> Option<T>,
+{
+    fn with<V, F: FnOnce(&String) -> V>(&self, data: &T, f: F) -> V {
+        f(&(self.format)(data))
+    }
+
+    fn with_mut<V, F: FnOnce(&mut String) -> V>(&self, data: &mut T, f: F) -> V {
+        let old = (self.format)(data);
+        let mut temp = old.clone();
+        let v = f(&mut temp);
+        if temp != old {
+            if let Some(new) = (self.parse)(&temp) {
+                *data = new;
+            }
+        }
+        v
+    }
+}
+
 /// `Lens` for invoking `Deref` and `DerefMut` on a type
 ///
 /// See also `LensExt::deref`.
@@ -427,6 +560,13 @@ where
 }
 
 /// `Lens` for indexing containers
+///
+/// # Panics
+///
+/// Like indexing the container directly, this lens panics if the index is
+/// out of bounds. If the index may be missing, use [`CheckedIndex`] instead.
+///
+/// [`CheckedIndex`]: struct.CheckedIndex.html
 #[derive(Debug, Copy, Clone)]
 pub struct Index<I> {
     index: I,
@@ -454,6 +594,55 @@ where
     }
 }
 
+/// `Lens` for an index in a `Vec` that may be out of bounds.
+///
+/// The lens exposes a copy of the element as an `Option`, which is `None`
+/// if the index is out of bounds. Writing through the lens updates the
+/// element if it exists; if the index is out of bounds, or the value is set
+/// to `None`, the write does nothing.
+///
+/// ```
+/// # use druid::*;
+/// let lens = lens::CheckedIndex::new(2);
+/// let mut data = vec![0u32, 1, 2];
+/// assert_eq!(lens.get(&data), Some(2));
+/// lens.put(&mut data, Some(42));
+/// assert_eq!(data, vec![0, 1, 42]);
+///
+/// let lens = lens::CheckedIndex::new(5);
+/// assert_eq!(lens.get(&data), None);
+/// lens.put(&mut data, Some(7));
+/// assert_eq!(data, vec![0, 1, 42]);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct CheckedIndex {
+    index: usize,
+}
+
+impl CheckedIndex {
+    /// Construct a lens that accesses a particular index, if it exists.
+    pub fn new(index: usize) -> Self {
+        Self { index }
+    }
+}
+
+impl<U: Data> Lens<Vec<U>, Option<U>> for CheckedIndex {
+    fn with<V, F: FnOnce(&Option<U>) -> V>(&self, data: &Vec<U>, f: F) -> V {
+        f(&data.get(self.index).cloned())
+    }
+
+    fn with_mut<V, F: FnOnce(&mut Option<U>) -> V>(&self, data: &mut Vec<U>, f: F) -> V {
+        let mut item = data.get(self.index).cloned();
+        let v = f(&mut item);
+        if let (Some(new), Some(old)) = (item, data.get_mut(self.index)) {
+            if !old.same(&new) {
+                *old = new;
+            }
+        }
+        v
+    }
+}
+
 /// The identity lens: the lens which does nothing, i.e. exposes exactly
 /// the original value.
 ///
@@ -554,10 +743,22 @@ where
 }
 
 /// A lens that always gives the same value and discards changes.
+///
+/// This is useful for showing a fixed value in a widget that expects to be
+/// lensed into the data, such as a display-only label or a disabled control.
+///
+/// ```
+/// # use druid::*;
+/// let lens = lens::Constant(42u32);
+/// let mut data = "anything";
+/// assert_eq!(lens.get(&data), 42);
+/// lens.put(&mut data, 7);
+/// assert_eq!(lens.get(&data), 42);
+/// ```
 #[derive(Debug, Copy, Clone)]
 pub struct Constant<T>(pub T);
 
-impl<A, B: Clone> Lens<A, B> for Constant<B> {
+impl<A: ?Sized, B: Clone> Lens<A, B> for Constant<B> {
     fn with<V, F: FnOnce(&B) -> V>(&self, _: &A, f: F) -> V {
         f(&self.0)
     }
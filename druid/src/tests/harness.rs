@@ -44,7 +44,9 @@ pub(crate) const DEFAULT_SIZE: Size = Size::new(400., 400.);
 ///
 /// if you want those functions run you will need to call them yourself.
 ///
-/// Also, timers don't work.  ¯\_(ツ)_/¯
+/// Also, timers don't fire on their own.  ¯\_(ツ)_/¯ You can still deliver
+/// one by sending an `Event::Timer` with the token the widget got from
+/// `request_timer`.
 pub struct Harness<'a, T> {
     piet: Piet<'a>,
     inner: Inner<T>,
@@ -267,6 +269,12 @@ impl<T: Data> Harness<'_, T> {
         self.inner.layout()
     }
 
+    /// Send an `AnimFrame` event, if any widget has requested one, the same
+    /// way it would happen right before painting.
+    pub fn animate(&mut self) {
+        self.inner.prepare_paint()
+    }
+
     /// Paints just the part of the window that was invalidated by calls to `request_paint` or
     /// `request_paint_rect`.
     ///
@@ -306,6 +314,12 @@ impl<T: Data> Inner<T> {
             .just_layout(&mut self.cmds, &self.data, &self.env);
     }
 
+    #[allow(dead_code)]
+    fn prepare_paint(&mut self) {
+        self.window
+            .prepare_paint(&mut self.cmds, &mut self.data, &self.env);
+    }
+
     #[allow(dead_code)]
     fn paint_region(&mut self, piet: &mut Piet, invalid: &Region) {
         self.window
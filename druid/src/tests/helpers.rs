@@ -16,6 +16,7 @@
 //!
 //! This includes tools for making throwaway widgets more easily.
 
+use std::any::Any;
 use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::rc::Rc;
@@ -29,6 +30,8 @@ pub type LayoutFn<S, T> = dyn FnMut(&mut S, &mut LayoutCtx, &BoxConstraints, &T,
 pub type PaintFn<S, T> = dyn FnMut(&mut S, &mut PaintCtx, &T, &Env);
 
 pub const REPLACE_CHILD: Selector = Selector::new("druid-test.replace-child");
+/// The new data for a [`data_setter`]; see [`set_data`].
+const SET_DATA: Selector<Box<dyn Any>> = Selector::new("druid-test.set-data");
 
 /// A widget that can be constructed from individual functions, builder-style.
 ///
@@ -341,3 +344,27 @@ pub fn widget_id6() -> (WidgetId, WidgetId, WidgetId, WidgetId, WidgetId, Widget
         WidgetId::next(),
     )
 }
+
+/// A widget that replaces its data with the payload of a [`set_data`]
+/// command, to change the data from outside the way user input would.
+///
+/// # Panics
+///
+/// Panics if the payload is not a `T`.
+pub fn data_setter<T: Data>() -> ModularWidget<(), T> {
+    ModularWidget::new(()).event_fn(|_, _, event, data: &mut T, _| {
+        if let Event::Command(cmd) = event {
+            if let Some(new_data) = cmd.get(SET_DATA) {
+                *data = new_data
+                    .downcast_ref::<T>()
+                    .expect("set_data payload has the wrong type")
+                    .clone();
+            }
+        }
+    })
+}
+
+/// A command that sets the data of a [`data_setter`] to `data`.
+pub fn set_data<T: Any>(data: T) -> Command {
+    SET_DATA.with(Box::new(data))
+}
@@ -125,3 +125,71 @@ fn invalidate_scroll() {
 }
 
 // TODO: one with scroll
+
+#[test]
+fn env_scope_change_invalidates_subtree() {
+    let id = WidgetId::next();
+    let child = ModularWidget::new(())
+        .event_fn(|_, _ctx, event, data: &mut bool, _env| {
+            if let Event::MouseDown(_) = event {
+                *data = !*data;
+            }
+        })
+        .layout_fn(|_, _ctx, bc, _data, _env| bc.max());
+    let widget = child.with_id(id).env_scope(|env, dark: &bool| {
+        let mode = if *dark {
+            theme::Mode::Dark
+        } else {
+            theme::Mode::Light
+        };
+        theme::set_mode(env, mode);
+    });
+
+    Harness::create_simple(true, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        harness.paint_invalid();
+        let child_rect = harness.get_state(id).layout_rect();
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        assert!(!*harness.data());
+        assert_eq!(harness.window().invalid().rects(), &[child_rect]);
+    });
+}
+
+#[test]
+fn paint_skips_widgets_outside_invalid_region() {
+    let clicked_rec = Recording::default();
+    let other_rec = Recording::default();
+
+    let clicked = ModularWidget::new(())
+        .event_fn(|_, ctx, event, _data, _env| {
+            if let Event::MouseDown(_) = event {
+                ctx.request_paint();
+            }
+        })
+        .layout_fn(|_, _ctx, bc, _data, _env| bc.constrain(Size::new(100., 100.)))
+        .record(&clicked_rec);
+    let other = SizedBox::empty()
+        .width(100.)
+        .height(100.)
+        .record(&other_rec);
+    // top-aligned, so that the click below lands on `clicked`
+    let widget = Flex::row()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(clicked)
+        .with_child(other);
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        harness.paint_invalid();
+        clicked_rec.clear();
+        other_rec.clear();
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.paint_invalid();
+        assert!(clicked_rec.drain().any(|r| matches!(r, Record::Paint)));
+        assert!(other_rec.drain().all(|r| !matches!(r, Record::Paint)));
+    });
+}
@@ -185,3 +185,516 @@ fn flex_paint_rect_overflow() {
         assert_eq!(state.paint_rect().size(), expected_paint_rect.size());
     })
 }
+
+#[test]
+/// Flex children share the space left over by non-flex children, in proportion
+/// to their flex factors.
+fn flex_proportional_space() {
+    let (id1, id2, id3) = widget_id3();
+    let widget = Flex::row()
+        .with_child(SizedBox::empty().width(100.).with_id(id1))
+        .with_flex_child(SizedBox::empty().expand().with_id(id2), 1.0)
+        .with_flex_child(SizedBox::empty().expand().with_id(id3), 2.0);
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        let state1 = harness.get_state(id1);
+        assert_eq!(state1.layout_rect().width(), 100.);
+        let state2 = harness.get_state(id2);
+        assert_eq!(state2.layout_rect().origin(), Point::new(100., 0.));
+        assert_eq!(state2.layout_rect().width(), 100.);
+        let state3 = harness.get_state(id3);
+        assert_eq!(state3.layout_rect().origin(), Point::new(200., 0.));
+        assert_eq!(state3.layout_rect().width(), 200.);
+    })
+}
+
+#[test]
+fn z_stack_sizes_to_largest_child() {
+    let (id1, id2, id3) = widget_id3();
+    let widget = ZStack::new()
+        .with_child(SizedBox::empty().width(100.).height(50.).with_id(id1))
+        .with_aligned_child(
+            SizedBox::empty().width(50.).height(100.).with_id(id2),
+            UnitPoint::BOTTOM_RIGHT,
+        )
+        .with_id(id3)
+        .center();
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        let stack = harness.get_state(id3);
+        assert_eq!(stack.layout_rect().size(), Size::new(100., 100.));
+        let first = harness.get_state(id1);
+        assert_eq!(first.layout_rect().origin(), Point::new(0., 25.));
+        let second = harness.get_state(id2);
+        assert_eq!(second.layout_rect().origin(), Point::new(50., 0.));
+    })
+}
+
+#[test]
+fn split_drag_respects_min_size() {
+    let (id1, id2) = widget_id2();
+    let widget = Split::columns(
+        SizedBox::empty().expand().with_id(id1),
+        SizedBox::empty().expand().with_id(id2),
+    )
+    .min_size(100., 100.)
+    .draggable(true);
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        // the default bar is 6px wide, leaving 394px for the children
+        assert_eq!(harness.get_state(id1).layout_rect().width(), 197.);
+
+        harness.event(Event::MouseDown(left_mouse((200., 10.))));
+        harness.event(Event::MouseMove(left_mouse((10., 10.))));
+        harness.just_layout();
+        assert_eq!(harness.get_state(id1).layout_rect().width(), 100.);
+        assert_eq!(harness.get_state(id2).layout_rect().width(), 294.);
+
+        harness.event(Event::MouseMove(left_mouse((390., 10.))));
+        harness.event(Event::MouseUp(left_mouse((390., 10.))));
+        harness.just_layout();
+        assert_eq!(harness.get_state(id1).layout_rect().width(), 294.);
+        assert_eq!(harness.get_state(id2).layout_rect().width(), 100.);
+    })
+}
+
+#[test]
+fn directional_padding() {
+    let (id1, id2) = widget_id2();
+    let widget = Flex::column()
+        .with_child(
+            SizedBox::empty()
+                .width(100.)
+                .height(100.)
+                .with_id(id1)
+                .padding_horizontal(10.),
+        )
+        .with_child(
+            SizedBox::empty()
+                .width(100.)
+                .height(100.)
+                .with_id(id2)
+                .padding_vertical(10.),
+        )
+        .cross_axis_alignment(CrossAxisAlignment::Start);
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(
+            harness.get_state(id1).layout_rect(),
+            Rect::new(10., 0., 110., 100.)
+        );
+        assert_eq!(
+            harness.get_state(id2).layout_rect(),
+            Rect::new(0., 10., 100., 110.)
+        );
+    })
+}
+
+#[test]
+fn spacer_sizes() {
+    let (id1, id2, id3) = widget_id3();
+    // spacers work with any data, so we have to say which we want
+    let fixed = WidgetExt::<()>::with_id(Spacer::fixed(20.), id1);
+    let flex = WidgetExt::<()>::with_id(Spacer::flex().axis(Axis::Horizontal), id2);
+    let widget = Flex::row()
+        .with_child(fixed)
+        .with_flex_child(flex, 1.0)
+        .with_child(SizedBox::empty().width(100.).height(50.).with_id(id3));
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(
+            harness.get_state(id1).layout_rect().size(),
+            Size::new(20., 20.)
+        );
+        // the flex spacer takes up the rest of the row, but no height
+        assert_eq!(
+            harness.get_state(id2).layout_rect().size(),
+            Size::new(280., 0.)
+        );
+        assert_eq!(harness.get_state(id3).layout_rect().x0, 300.);
+    })
+}
+
+#[test]
+fn layout_is_cached_until_requested() {
+    const RELAYOUT: Selector = Selector::new("druid-tests.relayout");
+    let (counted_id, other_id) = widget_id2();
+    let layout_count = Rc::new(Cell::new(0));
+
+    let relayout_on_command =
+        |_: &mut (), ctx: &mut EventCtx, event: &Event, _: &mut (), _: &Env| {
+            if matches!(event, Event::Command(cmd) if cmd.is(RELAYOUT)) {
+                ctx.request_layout();
+            }
+        };
+    let count = layout_count.clone();
+    let counted = ModularWidget::new(())
+        .event_fn(relayout_on_command)
+        .layout_fn(move |_, _ctx, bc, _data, _env| {
+            count.set(count.get() + 1);
+            bc.constrain(Size::new(10., 10.))
+        })
+        .with_id(counted_id);
+    let other = ModularWidget::new(())
+        .event_fn(relayout_on_command)
+        .layout_fn(|_, _ctx, bc, _data, _env| bc.constrain(Size::new(10., 10.)))
+        .with_id(other_id);
+    let widget = Flex::row().with_child(counted).with_child(other);
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(layout_count.get(), 1);
+
+        // a sibling asking for layout doesn't lay us out again
+        harness.submit_command(RELAYOUT.to(other_id));
+        harness.just_layout();
+        assert_eq!(layout_count.get(), 1);
+        assert_eq!(
+            harness.get_state(counted_id).layout_rect().size(),
+            Size::new(10., 10.)
+        );
+
+        harness.submit_command(RELAYOUT.to(counted_id));
+        harness.just_layout();
+        assert_eq!(layout_count.get(), 2);
+    })
+}
+
+#[test]
+fn moving_cached_child_updates_hot_state() {
+    const MOVE: Selector = Selector::new("druid-tests.move");
+    let (moved_id, inner_id) = widget_id2();
+    let offset = Rc::new(Cell::new(0.));
+    let offset_2 = offset.clone();
+
+    let inner = SizedBox::empty().width(100.).height(100.).with_id(inner_id);
+    let child = WidgetPod::new(Padding::new(0., inner).with_id(moved_id).boxed());
+    let widget = ModularWidget::new(child)
+        .event_fn(move |child, ctx, event, data, env| {
+            if matches!(event, Event::Command(cmd) if cmd.is(MOVE)) {
+                offset_2.set(200.);
+                ctx.request_layout();
+            }
+            child.event(ctx, event, data, env);
+        })
+        .lifecycle_fn(|child, ctx, event, data, env| child.lifecycle(ctx, event, data, env))
+        .layout_fn(move |child, ctx, bc, data, env| {
+            child.layout(ctx, &bc.loosen(), data, env);
+            child.set_origin(ctx, data, env, Point::new(offset.get(), 0.));
+            bc.max()
+        });
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        harness.event(Event::MouseMove(move_mouse((250., 50.))));
+        assert!(!harness.get_state(inner_id).is_hot);
+
+        // only the parent is laid out again, the child uses its cached size
+        harness.submit_command(MOVE);
+        harness.just_layout();
+        assert!(harness.get_state(moved_id).is_hot);
+        assert!(harness.get_state(inner_id).is_hot);
+    })
+}
+
+#[test]
+fn scroll_locks_cross_axis() {
+    let (id1, id2) = widget_id2();
+    // takes all the space it's given on the cross axis, and 1000 on the main axis
+    let tall = ModularWidget::new(())
+        .layout_fn(|_, _ctx, bc, _data, _env| Size::new(bc.max().width.min(1000.), 1000.))
+        .with_id(id1);
+    let wide = ModularWidget::new(())
+        .layout_fn(|_, _ctx, bc, _data, _env| Size::new(1000., bc.max().height.min(1000.)))
+        .with_id(id2);
+    let widget = Flex::column()
+        .with_flex_child(tall.scroll(), 1.0)
+        .with_flex_child(wide.scroll_horizontal(), 1.0);
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(
+            harness.get_state(id1).layout_rect().size(),
+            Size::new(400., 1000.)
+        );
+        assert_eq!(
+            harness.get_state(id2).layout_rect().size(),
+            Size::new(1000., 200.)
+        );
+    })
+}
+
+#[test]
+fn container_border_sides() {
+    let (id1, id2) = widget_id2();
+    let widget = Container::new(SizedBox::empty().width(100.).height(10.).with_id(id1))
+        .border_sides(Insets::new(0., 0., 5., 2.), Color::BLACK)
+        .with_id(id2)
+        .center();
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        // only the right and bottom sides take up space
+        assert_eq!(
+            harness.get_state(id1).layout_rect(),
+            Rect::new(0., 0., 100., 10.)
+        );
+        assert_eq!(
+            harness.get_state(id2).layout_rect().size(),
+            Size::new(105., 12.)
+        );
+    })
+}
+
+#[test]
+fn container_padding_inside_border() {
+    let (id1, id2) = widget_id2();
+    let widget = Container::new(SizedBox::empty().width(100.).height(10.).with_id(id1))
+        .border(Color::BLACK, 2.)
+        .inner_padding((10., 5.))
+        .with_id(id2)
+        .center();
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(
+            harness.get_state(id1).layout_rect(),
+            Rect::new(12., 7., 112., 17.)
+        );
+        assert_eq!(
+            harness.get_state(id2).layout_rect().size(),
+            Size::new(124., 24.)
+        );
+    })
+}
+
+#[test]
+fn set_origin_keeps_child_size() {
+    let id = WidgetId::next();
+    let child = WidgetPod::new(SizedBox::empty().width(20.).height(10.).with_id(id).boxed());
+    let widget = ModularWidget::new(child)
+        .lifecycle_fn(|child, ctx, event, data, env| child.lifecycle(ctx, event, data, env))
+        .layout_fn(|child, ctx, bc, data, env| {
+            child.layout(ctx, &bc.loosen(), data, env);
+            child.set_origin(ctx, data, env, Point::new(30., 40.));
+            assert_eq!(child.layout_rect(), Rect::new(30., 40., 50., 50.));
+            bc.max()
+        });
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(
+            harness.get_state(id).layout_rect(),
+            Rect::new(30., 40., 50., 50.)
+        );
+    })
+}
+
+#[test]
+fn flex_main_axis_alignment() {
+    let (id1, id2, id3) = widget_id3();
+    let button_bar = |alignment| {
+        Flex::row()
+            .main_axis_alignment(alignment)
+            .must_fill_main_axis(true)
+            .with_child(SizedBox::empty().width(50.).with_id(id1))
+            .with_child(SizedBox::empty().width(50.).with_id(id2))
+            .with_child(SizedBox::empty().width(50.).with_id(id3))
+    };
+    let x_positions = |harness: &mut Harness<()>| {
+        [id1, id2, id3]
+            .iter()
+            .map(|id| harness.get_state(*id).layout_rect().x0)
+            .collect::<Vec<_>>()
+    };
+
+    // the row is 400 wide, so there are 250 left over
+    let cases = [
+        (MainAxisAlignment::Start, [0., 50., 100.]),
+        (MainAxisAlignment::Center, [125., 175., 225.]),
+        (MainAxisAlignment::End, [250., 300., 350.]),
+        (MainAxisAlignment::SpaceBetween, [0., 175., 350.]),
+        (MainAxisAlignment::SpaceAround, [42., 175., 308.]),
+    ];
+    for (alignment, expected) in cases.iter() {
+        Harness::create_simple((), button_bar(*alignment), |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            assert_eq!(x_positions(harness), expected, "{:?}", alignment);
+        });
+    }
+}
+
+#[test]
+/// Children that ask for baseline alignment should line up on their
+/// baselines even if the row itself uses a different alignment.
+fn flex_child_baseline_alignment() {
+    let (row_id, id1, id2) = widget_id3();
+    let with_baseline = |size: Size, baseline: f64| {
+        ModularWidget::new(()).layout_fn(move |_, ctx, bc, _, _| {
+            ctx.set_baseline_offset(baseline);
+            bc.constrain(size)
+        })
+    };
+    let baseline = FlexParams::new(0.0, CrossAxisAlignment::Baseline);
+
+    let widget = Flex::row()
+        .with_flex_child(
+            with_baseline(Size::new(20., 30.), 5.).with_id(id1),
+            baseline,
+        )
+        .with_flex_child(
+            with_baseline(Size::new(20., 20.), 15.).with_id(id2),
+            baseline,
+        )
+        .with_id(row_id)
+        .center();
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        // 25 above the tallest baseline, 15 below the deepest one
+        let row = harness.get_state(row_id);
+        assert_eq!(row.layout_rect().height(), 40.);
+
+        let first = harness.get_state(id1);
+        let second = harness.get_state(id2);
+        assert_eq!(
+            first.layout_rect().y1 - first.baseline_offset,
+            second.layout_rect().y1 - second.baseline_offset,
+        );
+    });
+}
+
+#[test]
+fn flex_overflow_clip() {
+    let (visible_id, clip_id) = widget_id2();
+    let overflowing_row = |overflow, id| {
+        Flex::row()
+            .with_child(SizedBox::empty().width(80.))
+            .with_child(SizedBox::empty().width(80.))
+            .overflow(overflow)
+            .with_id(id)
+            .fix_width(100.)
+    };
+
+    let widget = Flex::column()
+        .with_child(overflowing_row(Overflow::Visible, visible_id))
+        .with_child(overflowing_row(Overflow::Clip, clip_id));
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        let visible = harness.get_state(visible_id);
+        assert_eq!(visible.paint_insets.x1, 60.);
+
+        let clip = harness.get_state(clip_id);
+        assert_eq!(clip.layout_rect().width(), 100.);
+        assert_eq!(clip.paint_insets, Insets::ZERO);
+    });
+}
+
+#[test]
+fn flex_must_fill_main_axis() {
+    let (row_id, column_id) = widget_id2();
+    let filled_row = Flex::row()
+        .must_fill_main_axis(true)
+        .with_child(SizedBox::empty().width(50.).height(10.))
+        .background(Color::BLACK)
+        .with_id(row_id);
+    let unbounded_column = Flex::column()
+        .must_fill_main_axis(true)
+        .with_child(SizedBox::empty().width(50.).height(10.))
+        .with_id(column_id);
+
+    // the outer column is 400 wide, but unbounded vertically
+    let widget = Flex::column()
+        .with_child(filled_row)
+        .with_child(unbounded_column);
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        let row = harness.get_state(row_id).layout_rect();
+        assert_eq!(row.size(), Size::new(400., 10.));
+
+        let column = harness.get_state(column_id).layout_rect();
+        assert_eq!(column.size(), Size::new(50., 10.));
+    });
+}
+
+#[test]
+fn grid_places_children_in_cells() {
+    let (label, field, footer) = widget_id3();
+    let widget = Grid::new(
+        vec![TrackSize::Auto, TrackSize::Flex(1.)],
+        vec![TrackSize::Fixed(30.), TrackSize::Flex(1.)],
+    )
+    .with_spacing(10.)
+    .with_child(SizedBox::empty().width(50.).with_id(label), 0, 0)
+    .with_child(SizedBox::empty().with_id(field), 0, 1)
+    .with_spanning_child(SizedBox::empty().with_id(footer), 1, 0, 1, 2);
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        assert_eq!(
+            harness.get_state(label).layout_rect(),
+            Rect::new(0., 0., 50., 30.)
+        );
+        assert_eq!(
+            harness.get_state(field).layout_rect(),
+            Rect::new(60., 0., 400., 30.)
+        );
+        assert_eq!(
+            harness.get_state(footer).layout_rect(),
+            Rect::new(0., 40., 400., 400.)
+        );
+    });
+}
+
+#[test]
+fn align_shrink_to_fit() {
+    let (filled, shrunk, child) = widget_id3();
+    let widget = Flex::column()
+        .with_child(Align::centered(SizedBox::empty().width(50.).height(50.)).with_id(filled))
+        .with_child(
+            Align::centered(SizedBox::empty().width(50.).height(50.).with_id(child))
+                .shrink_to_fit()
+                .with_id(shrunk),
+        );
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        // the column is unbounded vertically, but not horizontally
+        let filled = harness.get_state(filled).layout_rect();
+        assert_eq!(filled.size(), Size::new(400., 50.));
+
+        let shrunk = harness.get_state(shrunk).layout_rect();
+        assert_eq!(shrunk.size(), Size::new(50., 50.));
+        let child = harness.get_state(child).layout_rect();
+        assert_eq!(child.origin(), Point::ZERO);
+    });
+}
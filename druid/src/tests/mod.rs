@@ -15,14 +15,16 @@
 //! Additional unit tests that cross file or module boundaries.
 
 pub(crate) mod harness;
-mod helpers;
+pub(crate) mod helpers;
 mod invalidation_tests;
 mod layout_tests;
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::env;
 use std::fs;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::widget::*;
 use crate::*;
@@ -58,6 +60,20 @@ fn scroll_mouse(p: impl Into<Point>, delta: impl Into<Vec2>) -> MouseEvent {
     }
 }
 
+fn left_mouse(p: impl Into<Point>) -> MouseEvent {
+    let pos = p.into();
+    MouseEvent {
+        pos,
+        window_pos: pos,
+        buttons: MouseButtons::default().with(MouseButton::Left),
+        mods: Modifiers::default(),
+        count: 1,
+        focus: false,
+        button: MouseButton::Left,
+        wheel_delta: Vec2::ZERO,
+    }
+}
+
 /// This function creates a temporary directory and returns a PathBuf to it.
 ///
 /// This directory will be created relative to the executable and will therefor
@@ -246,6 +262,49 @@ fn take_focus() {
     })
 }
 
+#[test]
+fn key_events_go_to_focused_widget() {
+    const TAKE_FOCUS: Selector = Selector::new("druid-tests.take-focus");
+    const RESIGN_FOCUS: Selector = Selector::new("druid-tests.resign-focus");
+
+    /// A widget that counts the key events it receives, and that takes or
+    /// gives up focus on command.
+    fn make_key_counter(keys: Rc<Cell<usize>>) -> impl Widget<bool> {
+        ModularWidget::new(keys).event_fn(|keys, ctx, event, _data, _env| match event {
+            Event::Command(cmd) if cmd.is(TAKE_FOCUS) => ctx.request_focus(),
+            Event::Command(cmd) if cmd.is(RESIGN_FOCUS) => ctx.resign_focus(),
+            Event::KeyDown(_) => keys.set(keys.get() + 1),
+            _ => (),
+        })
+    }
+
+    let (id_1, id_2) = widget_id2();
+    let left_keys: Rc<Cell<usize>> = Default::default();
+    let right_keys: Rc<Cell<usize>> = Default::default();
+
+    let left = make_key_counter(left_keys.clone()).with_id(id_1);
+    let right = make_key_counter(right_keys.clone()).with_id(id_2);
+    let app = Split::columns(left, right);
+
+    Harness::create_simple(true, app, |harness| {
+        harness.send_initial_events();
+        let key = KeyEvent::for_test(Modifiers::default(), "a");
+
+        // without focus, nobody gets key events
+        harness.event(Event::KeyDown(key.clone()));
+        assert_eq!((left_keys.get(), right_keys.get()), (0, 0));
+
+        harness.submit_command(TAKE_FOCUS.to(id_1));
+        harness.event(Event::KeyDown(key.clone()));
+        assert_eq!((left_keys.get(), right_keys.get()), (1, 0));
+
+        harness.submit_command(RESIGN_FOCUS.to(id_1));
+        assert_eq!(harness.window().focus, None);
+        harness.event(Event::KeyDown(key));
+        assert_eq!((left_keys.get(), right_keys.get()), (1, 0));
+    })
+}
+
 #[test]
 fn focus_changed() {
     const TAKE_FOCUS: Selector = Selector::new("druid-tests.take-focus");
@@ -522,6 +581,123 @@ fn request_update() {
     })
 }
 
+#[test]
+fn drag_and_drop() {
+    let (source_id, target_id) = widget_id2();
+    let widget = Flex::row()
+        .with_child(
+            SizedBox::empty()
+                .width(100.)
+                .height(100.)
+                .draggable(|_, _| 7u32)
+                .with_id(source_id),
+        )
+        .with_child(
+            SizedBox::empty()
+                .width(100.)
+                .height(100.)
+                .drop_target(
+                    |n: &u32, _| *n > 5,
+                    |_ctx, n, data: &mut u32, _env| *data += n,
+                )
+                .with_id(target_id),
+        )
+        .with_child(SizedBox::empty().width(100.).height(100.));
+
+    Harness::create_simple(0u32, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        let source = harness.get_state(source_id).layout_rect().center();
+        let target = harness.get_state(target_id).layout_rect().center();
+
+        // a click is not a drag
+        harness.event(Event::MouseDown(left_mouse(source)));
+        harness.event(Event::MouseUp(left_mouse(source)));
+        harness.event(Event::MouseMove(move_mouse(target)));
+        harness.event(Event::MouseUp(left_mouse(target)));
+        assert_eq!(*harness.data(), 0);
+
+        harness.event(Event::MouseDown(left_mouse(source)));
+        harness.event(Event::MouseMove(move_mouse(target)));
+        harness.event(Event::MouseUp(left_mouse(target)));
+        assert_eq!(*harness.data(), 7);
+
+        // the drag ended with the last mouse up
+        harness.event(Event::MouseUp(left_mouse(target)));
+        assert_eq!(*harness.data(), 7);
+
+        // dropped elsewhere
+        harness.event(Event::MouseDown(left_mouse(source)));
+        harness.event(Event::MouseMove(move_mouse(target + (100., 0.))));
+        harness.event(Event::MouseUp(left_mouse(target + (100., 0.))));
+        assert_eq!(*harness.data(), 7);
+    })
+}
+
+#[test]
+/// Test that request_update also works from lifecycle, e.g. on hover.
+fn request_update_from_lifecycle() {
+    let updated: Rc<Cell<bool>> = Default::default();
+    let updated_clone = updated.clone();
+
+    let widget = ModularWidget::new(())
+        .lifecycle_fn(|_, ctx, event, _data, _env| {
+            if let LifeCycle::HotChanged(true) = event {
+                ctx.request_update();
+            }
+        })
+        .update_fn(move |_, _ctx, _old_data, _data, _env| {
+            updated_clone.set(true);
+        });
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert!(!updated.get());
+        harness.event(Event::MouseMove(move_mouse((10., 10.))));
+        assert!(updated.get());
+    })
+}
+
+#[test]
+/// Test that `update` isn't called on children whose data didn't change.
+fn update_skips_unchanged_children() {
+    const BUMP: Selector = Selector::new("druid-tests.bump");
+    let updates: Rc<Cell<(u32, u32)>> = Default::default();
+    let first_updates = updates.clone();
+    let second_updates = updates.clone();
+
+    let first = ModularWidget::new(())
+        .event_fn(|_, _ctx, event, data: &mut u32, _env| {
+            if matches!(event, Event::Command(cmd) if cmd.is(BUMP)) {
+                *data += 1;
+            }
+        })
+        .update_fn(move |_, _ctx, _old_data, _data, _env| {
+            let (first, second) = first_updates.get();
+            first_updates.set((first + 1, second));
+        });
+    let second = ModularWidget::new(()).update_fn(move |_, _ctx, _old_data, _data, _env| {
+        let (first, second) = second_updates.get();
+        second_updates.set((first, second + 1));
+    });
+
+    let widget = Flex::column()
+        .with_child(first.lens(lens!((u32, u32), 0)))
+        .with_child(second.lens(lens!((u32, u32), 1)));
+
+    Harness::create_simple((0, 0), widget, |harness| {
+        harness.send_initial_events();
+        assert_eq!(updates.get(), (0, 0));
+
+        harness.submit_command(BUMP);
+        assert_eq!(harness.data(), &(1, 0));
+        assert_eq!(updates.get(), (1, 0));
+
+        harness.submit_command(BUMP);
+        assert_eq!(updates.get(), (2, 0));
+    })
+}
+
 #[test]
 /// Ensure that notifications are delivered to ancestors, but not siblings.
 fn notifications() {
@@ -555,3 +731,1263 @@ fn notifications() {
         assert!(saw_notification(&grandparent_rec));
     });
 }
+
+#[test]
+/// Ensure that a command submitted by a widget reaches its target, with its payload.
+fn widget_commands_reach_target() {
+    const ADD_VALUE: Selector<u32> = Selector::new("druid-tests.add-value");
+    let (receiver_id, other_id) = widget_id2();
+
+    let sender = ModularWidget::new(()).event_fn(move |_, ctx, event, _, _| {
+        if matches!(event, Event::WindowConnected) {
+            ctx.submit_command(ADD_VALUE.with(42).to(receiver_id));
+        }
+    });
+    let make_receiver = |id| {
+        ModularWidget::new(())
+            .event_fn(|_, _ctx, event, data: &mut u32, _| {
+                if let Event::Command(cmd) = event {
+                    if let Some(value) = cmd.get(ADD_VALUE) {
+                        *data += *value;
+                    }
+                }
+            })
+            .with_id(id)
+    };
+
+    let tree = Flex::row()
+        .with_child(sender)
+        .with_child(make_receiver(receiver_id))
+        .with_child(make_receiver(other_id));
+
+    Harness::create_simple(0u32, tree, |harness| {
+        harness.send_initial_events();
+        // only the target widget saw the command, and only once
+        assert_eq!(*harness.data(), 42);
+    });
+}
+
+#[test]
+/// Ensure that a command targeting a nested widget is delivered to only that widget.
+fn targeted_command_reaches_nested_widget() {
+    const PING: Selector = Selector::new("druid-tests.ping");
+    let target_id = WidgetId::next();
+
+    let target_rec = Recording::default();
+    let other_rec = Recording::default();
+
+    let tree = Flex::row()
+        .with_child(
+            Flex::column()
+                .with_child(SizedBox::empty().record(&target_rec).with_id(target_id))
+                .padding(10.),
+        )
+        .with_child(
+            Flex::column()
+                .with_child(SizedBox::empty())
+                .record(&other_rec),
+        );
+
+    let commands = |rec: &Recording| {
+        rec.drain()
+            .filter(|ev| matches!(ev, Record::E(Event::Command(_))))
+            .count()
+    };
+    Harness::create_simple((), tree, |harness| {
+        harness.send_initial_events();
+        target_rec.clear();
+        other_rec.clear();
+
+        harness.submit_command(PING.to(target_id));
+        assert_eq!(commands(&target_rec), 1);
+        assert_eq!(commands(&other_rec), 0);
+    });
+}
+
+#[test]
+/// Ensure that a button's click handler only fires if the mouse is released over it.
+fn button_click() {
+    let widget = Button::new("click").on_click(|_ctx, data: &mut u32, _env| *data += 1);
+
+    Harness::create_simple(0u32, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(*harness.data(), 1);
+
+        // releasing outside of the button cancels the click
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseMove(left_mouse((500., 500.))));
+        harness.event(Event::MouseUp(left_mouse((500., 500.))));
+        assert_eq!(*harness.data(), 1);
+        harness.inspect_state(|state| assert!(!state.is_active));
+    });
+}
+
+#[test]
+/// Clicking a `TextBox` should give it keyboard focus.
+fn textbox_click_takes_focus() {
+    let (id_1, id_2) = widget_id2();
+    let widget = Split::columns(TextBox::new().with_id(id_1), TextBox::new().with_id(id_2));
+
+    Harness::create_simple(String::new(), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(harness.window().focus, None);
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(harness.window().focus, Some(id_1));
+
+        harness.event(Event::MouseDown(left_mouse((390., 10.))));
+        harness.event(Event::MouseUp(left_mouse((390., 10.))));
+        assert_eq!(harness.window().focus, Some(id_2));
+    });
+}
+
+#[test]
+/// `ViewSwitcher` should only rebuild its child when the picked value changes.
+fn view_switcher_rebuilds_on_change() {
+    let builds: Rc<Cell<usize>> = Default::default();
+    let builds_clone = builds.clone();
+
+    let switcher = ViewSwitcher::new(
+        |data: &u32, _env| *data / 10,
+        move |_, _data, _env| {
+            builds_clone.set(builds_clone.get() + 1);
+            Label::new("child").boxed()
+        },
+    );
+    let widget = Flex::row().with_child(data_setter()).with_child(switcher);
+
+    Harness::create_simple(1u32, widget, |harness| {
+        harness.send_initial_events();
+        assert_eq!(builds.get(), 1);
+
+        // still maps to the same child
+        harness.submit_command(set_data(5u32));
+        assert_eq!(builds.get(), 1);
+
+        harness.submit_command(set_data(15u32));
+        assert_eq!(builds.get(), 2);
+    });
+}
+
+#[test]
+/// Both branches of an `Either` are kept up to date, and switching branches
+/// lays out the newly shown one with the latest data.
+fn either_updates_both_branches() {
+    let true_rec = Recording::default();
+    let false_rec = Recording::default();
+    let shown: Rc<Cell<Option<u32>>> = Default::default();
+    let shown2 = shown.clone();
+
+    // shows the data it last saw in `update`, like a widget that keeps state
+    // derived from its data
+    let last_seen = ModularWidget::new(0u32)
+        .update_fn(|seen, _ctx, _old, data: &u32, _env| *seen = *data)
+        .paint_fn(move |seen, _ctx, _data, _env| shown2.set(Some(*seen)))
+        .lens(lens!((bool, u32), 1));
+    let either = Either::new(
+        |data: &(bool, u32), _env| data.0,
+        SizedBox::empty().record(&true_rec),
+        last_seen.record(&false_rec),
+    );
+    let widget = Flex::row().with_child(data_setter()).with_child(either);
+
+    Harness::create_simple((true, 0), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        true_rec.clear();
+        false_rec.clear();
+
+        // the hidden branch sees the new data too
+        harness.submit_command(set_data((true, 1u32)));
+        assert!(true_rec.drain().any(|r| matches!(r, Record::Update(_))));
+        assert!(false_rec.drain().any(|r| matches!(r, Record::Update(_))));
+
+        // switch branches and change the data in one go
+        harness.submit_command(set_data((false, 2u32)));
+        harness.just_layout();
+        assert!(false_rec.drain().any(|r| matches!(r, Record::Layout(_))));
+        assert!(true_rec.drain().all(|r| !matches!(r, Record::Layout(_))));
+
+        // the newly shown branch paints the latest data
+        harness.paint();
+        assert_eq!(shown.get(), Some(2));
+    });
+}
+
+#[test]
+/// A click only counts if the mouse is released over the widget that
+/// saw the mouse go down.
+fn on_click_requires_same_widget() {
+    let widget = Split::columns(
+        Label::new("left").on_click(|_ctx, data: &mut (u32, u32), _env| data.0 += 1),
+        Label::new("right").on_click(|_ctx, data: &mut (u32, u32), _env| data.1 += 1),
+    );
+
+    Harness::create_simple((0, 0), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(*harness.data(), (1, 0));
+
+        // press on the left, release on the right
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseMove(left_mouse((390., 10.))));
+        harness.event(Event::MouseUp(left_mouse((390., 10.))));
+        assert_eq!(*harness.data(), (1, 0));
+
+        harness.event(Event::MouseDown(left_mouse((390., 10.))));
+        harness.event(Event::MouseUp(left_mouse((390., 10.))));
+        assert_eq!(*harness.data(), (1, 1));
+    });
+}
+
+#[test]
+fn stacked_on_added_all_run() {
+    let widget = Label::new("hello")
+        .on_added(|_ctx, data: &mut (u32, u32), _env| data.0 += 1)
+        .on_added(|_ctx, data: &mut (u32, u32), _env| data.1 += 1);
+
+    Harness::create_simple((0, 0), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(*harness.data(), (1, 1));
+    });
+}
+
+#[test]
+fn state_scope_follows_hot_and_active() {
+    const STATE: Key<f64> = Key::new("druid-tests.interaction-state");
+
+    let seen = Rc::new(Cell::new(-1.0));
+    let seen2 = seen.clone();
+    let child = ModularWidget::new(())
+        .lifecycle_fn(move |_, _, event, _data, env| {
+            if let LifeCycle::WidgetAdded = event {
+                seen2.set(env.get(STATE));
+            }
+        })
+        .update_fn({
+            let seen = seen.clone();
+            move |_, ctx, _old_data, _data, env| {
+                if ctx.env_key_changed(&STATE) {
+                    seen.set(env.get(STATE));
+                }
+            }
+        });
+    let widget = child
+        .state_scope(|env, _data, state| {
+            let value = match (state.is_hot, state.is_active) {
+                (_, true) => 2.0,
+                (true, false) => 1.0,
+                (false, false) => 0.0,
+            };
+            env.set(STATE, value);
+        })
+        .on_click(|_ctx, _data, _env| {});
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(seen.get(), 0.0);
+
+        harness.event(Event::MouseMove(move_mouse((10., 10.))));
+        assert_eq!(seen.get(), 1.0);
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        assert_eq!(seen.get(), 2.0);
+
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(seen.get(), 1.0);
+    });
+}
+
+/// A disabled widget doesn't get clicks.
+#[test]
+fn disabled_if_swallows_input() {
+    // the label disables itself after the first click
+    let widget = Label::new("once")
+        .on_click(|_ctx, data: &mut u32, _env| *data += 1)
+        .disabled_if(|data, _env| *data > 0);
+
+    Harness::create_simple(0, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(*harness.data(), 1);
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(*harness.data(), 1);
+    });
+}
+
+/// A disabled widget still follows the mouse, so that its hot state is
+/// right once it is enabled again.
+#[test]
+fn disabled_if_tracks_hot_state() {
+    let id = WidgetId::next();
+    let widget = SizedBox::empty()
+        .expand()
+        .with_id(id)
+        .disabled_if(|data: &bool, _env| *data);
+
+    Harness::create_simple(true, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert!(!harness.get_state(id).is_hot);
+
+        harness.event(Event::MouseMove(move_mouse((10., 10.))));
+        assert!(harness.get_state(id).is_hot);
+    });
+}
+
+#[test]
+/// `List` should add and remove children to match its data, reusing the
+/// children it already has.
+fn list_tracks_data_len() {
+    let builds: Rc<Cell<usize>> = Default::default();
+    let builds_clone = builds.clone();
+    let list_id = WidgetId::next();
+
+    let list = List::new(move || {
+        builds_clone.set(builds_clone.get() + 1);
+        SizedBox::empty().fix_size(10., 10.)
+    });
+    let list = WidgetExt::<Arc<Vec<u32>>>::with_id(list, list_id);
+    let widget = Flex::column().with_child(data_setter()).with_child(list);
+
+    Harness::create_simple(Arc::new(vec![0, 0]), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(builds.get(), 2);
+        assert_eq!(harness.get_state(list_id).layout_rect().height(), 20.);
+
+        harness.submit_command(set_data(Arc::new(vec![0u32; 3])));
+        harness.just_layout();
+        assert_eq!(builds.get(), 3);
+        assert_eq!(harness.get_state(list_id).layout_rect().height(), 30.);
+
+        harness.submit_command(set_data(Arc::new(vec![0u32; 1])));
+        harness.submit_command(set_data(Arc::new(vec![0u32; 2])));
+        harness.just_layout();
+        assert_eq!(builds.get(), 4);
+        assert_eq!(harness.get_state(list_id).layout_rect().height(), 20.);
+
+        harness.submit_command(set_data(Arc::new(vec![0u32; 0])));
+        harness.just_layout();
+        assert_eq!(harness.get_state(list_id).layout_rect().height(), 0.);
+    });
+}
+
+#[test]
+fn timer_is_delivered_to_requesting_widget() {
+    /// Requests two timers when connected, and counts the events for the
+    /// second one in the data.
+    fn make_timer_widget(token: Rc<Cell<TimerToken>>) -> impl Widget<u32> {
+        ModularWidget::new(token).event_fn(|token, ctx, event, data: &mut u32, _env| match event {
+            Event::WindowConnected => {
+                ctx.request_timer(Duration::from_millis(10));
+                token.set(ctx.request_timer(Duration::from_millis(20)));
+            }
+            Event::Timer(fired) if *fired == token.get() => *data += 1,
+            _ => (),
+        })
+    }
+
+    let token: Rc<Cell<TimerToken>> = Rc::new(Cell::new(TimerToken::INVALID));
+    let other_token: Rc<Cell<TimerToken>> = Rc::new(Cell::new(TimerToken::INVALID));
+    let widget = Flex::row()
+        .with_child(make_timer_widget(token.clone()))
+        .with_child(make_timer_widget(other_token.clone()));
+
+    Harness::create_simple(0, widget, |harness| {
+        harness.send_initial_events();
+        assert_ne!(token.get(), other_token.get());
+
+        harness.event(Event::Timer(token.get()));
+        assert_eq!(*harness.data(), 1);
+
+        // a timer fires only once
+        harness.event(Event::Timer(token.get()));
+        assert_eq!(*harness.data(), 1);
+    });
+}
+
+#[test]
+fn debounce_reports_only_last_change() {
+    const INCREMENT: Selector = Selector::new("druid-tests.debounce-increment");
+    let calls: Rc<RefCell<Vec<u32>>> = Default::default();
+
+    let calls_ = calls.clone();
+    let widget = ModularWidget::new(())
+        .event_fn(|_, _, event, data: &mut u32, _| {
+            if let Event::Command(cmd) = event {
+                if cmd.is(INCREMENT) {
+                    *data += 1;
+                }
+            }
+        })
+        .debounce(Duration::from_millis(100), move |data, _| {
+            calls_.borrow_mut().push(*data)
+        });
+
+    Harness::create_simple(0, widget, |harness| {
+        harness.send_initial_events();
+        harness.submit_command(INCREMENT);
+        let first = *harness.window().timers.keys().next().unwrap();
+        harness.submit_command(INCREMENT);
+        let last = *harness
+            .window()
+            .timers
+            .keys()
+            .find(|token| **token != first)
+            .unwrap();
+
+        // the first change was superseded
+        harness.event(Event::Timer(first));
+        assert!(calls.borrow().is_empty());
+        harness.event(Event::Timer(last));
+        assert_eq!(*calls.borrow(), vec![2]);
+    });
+}
+
+#[test]
+fn anim_frame_is_delivered_on_request() {
+    let frames: Rc<Cell<usize>> = Default::default();
+    let widget = ModularWidget::new(frames.clone())
+        .lifecycle_fn(|_, ctx, event, _data, _env| {
+            if let LifeCycle::WidgetAdded = event {
+                ctx.request_anim_frame();
+            }
+        })
+        .event_fn(|frames, ctx, event, _data, _env| {
+            if let Event::AnimFrame(interval) = event {
+                if frames.get() == 0 {
+                    assert_eq!(*interval, 0);
+                }
+                frames.set(frames.get() + 1);
+                if frames.get() < 3 {
+                    ctx.request_anim_frame();
+                }
+            }
+        });
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        for _ in 0..5 {
+            harness.animate();
+        }
+        assert_eq!(frames.get(), 3);
+    });
+}
+
+#[test]
+fn env_scope_only_affects_descendants() {
+    const SCOPED: Key<Color> = Key::new("druid-tests.env-scope-color");
+
+    /// A widget that records the color it sees when added, as rgba.
+    fn make_reader(seen: Rc<Cell<Option<u32>>>) -> impl Widget<()> {
+        ModularWidget::new(seen).lifecycle_fn(|seen, _ctx, event, _data, env| {
+            if let LifeCycle::WidgetAdded = event {
+                seen.set(Some(env.get(SCOPED).as_rgba_u32()));
+            }
+        })
+    }
+
+    let outside: Rc<Cell<Option<u32>>> = Default::default();
+    let inside: Rc<Cell<Option<u32>>> = Default::default();
+    let inherited: Rc<Cell<Option<u32>>> = Default::default();
+
+    let inherited_clone = inherited.clone();
+    let scoped = make_reader(inside.clone()).env_scope(move |env, _| {
+        // the closure should see the value set further up the tree
+        inherited_clone.set(Some(env.get(SCOPED).as_rgba_u32()));
+        env.set(SCOPED, Color::BLACK);
+    });
+    let widget = Flex::row()
+        .with_child(make_reader(outside.clone()))
+        .with_child(scoped)
+        .env_scope(|env, _| env.set(SCOPED, Color::WHITE));
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        assert_eq!(inherited.get(), Some(0xff_ff_ff_ff));
+        assert_eq!(outside.get(), Some(0xff_ff_ff_ff));
+        assert_eq!(inside.get(), Some(0x00_00_00_ff));
+    });
+}
+
+#[test]
+fn maybe_switches_children() {
+    let (some_id, none_id) = widget_id2();
+    let seen: Rc<Cell<Option<u32>>> = Default::default();
+
+    let some = ModularWidget::new(seen.clone())
+        .lifecycle_fn(|seen, _ctx, event, data: &u32, _env| {
+            if let LifeCycle::WidgetAdded = event {
+                seen.set(Some(*data));
+            }
+        })
+        .update_fn(|seen, _ctx, _old, data, _env| seen.set(Some(*data)))
+        .with_id(some_id);
+    let none = SizedBox::empty().with_id(none_id);
+    let widget = Flex::column()
+        .with_child(data_setter())
+        .with_child(Maybe::new(some, none));
+
+    Harness::create_simple(None, widget, |harness| {
+        harness.send_initial_events();
+        assert!(harness.try_get_state(none_id).is_some());
+        assert!(harness.try_get_state(some_id).is_none());
+
+        harness.submit_command(set_data(Some(5u32)));
+        assert_eq!(seen.get(), Some(5));
+        assert!(harness.try_get_state(some_id).is_some());
+        assert!(harness.try_get_state(none_id).is_none());
+
+        harness.submit_command(set_data(Some(7u32)));
+        assert_eq!(seen.get(), Some(7));
+
+        harness.submit_command(set_data(None::<u32>));
+        assert!(harness.try_get_state(none_id).is_some());
+        assert!(harness.try_get_state(some_id).is_none());
+    });
+}
+
+#[test]
+fn controller_decides_what_to_forward() {
+    /// Swallows mouse events, and counts the `WidgetAdded` it sees.
+    struct BlockMouse(Rc<Cell<usize>>);
+
+    impl<W: Widget<()>> Controller<(), W> for BlockMouse {
+        fn event(
+            &mut self,
+            child: &mut W,
+            ctx: &mut EventCtx,
+            event: &Event,
+            data: &mut (),
+            env: &Env,
+        ) {
+            if !matches!(event, Event::MouseDown(_) | Event::MouseUp(_)) {
+                child.event(ctx, event, data, env)
+            }
+        }
+
+        fn lifecycle(
+            &mut self,
+            child: &mut W,
+            ctx: &mut LifeCycleCtx,
+            event: &LifeCycle,
+            data: &(),
+            env: &Env,
+        ) {
+            if let LifeCycle::WidgetAdded = event {
+                self.0.set(self.0.get() + 1);
+            }
+            child.lifecycle(ctx, event, data, env)
+        }
+    }
+
+    let added: Rc<Cell<usize>> = Default::default();
+    let clicks: Rc<Cell<usize>> = Default::default();
+    let clicks_clone = clicks.clone();
+    let widget = ModularWidget::new(())
+        .event_fn(move |_, _ctx, event, _data, _env| {
+            if let Event::MouseDown(_) = event {
+                clicks_clone.set(clicks_clone.get() + 1);
+            }
+        })
+        .controller(BlockMouse(added.clone()));
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        assert_eq!(added.get(), 1);
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(clicks.get(), 0);
+    });
+}
+
+#[test]
+fn clip_box_hides_overflowing_child() {
+    let clicks: Rc<Cell<usize>> = Default::default();
+    let clicks_clone = clicks.clone();
+    let child = ModularWidget::new(())
+        .event_fn(move |_, _ctx, event, _data, _env| {
+            if let Event::MouseDown(_) = event {
+                clicks_clone.set(clicks_clone.get() + 1);
+            }
+        })
+        .layout_fn(|_, _ctx, _bc, _data, _env| Size::new(200., 200.));
+    // the clip box sits at (150, 150), and its child overflows it by 100 in each direction
+    let widget = ClipBox::new(child)
+        .constrain_horizontal(true)
+        .constrain_vertical(true)
+        .rounded(50.)
+        .fix_size(100., 100.)
+        .center();
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        // outside the clip box, but inside the child
+        harness.event(Event::MouseDown(left_mouse((300., 200.))));
+        assert_eq!(clicks.get(), 0);
+
+        // inside the clip box, but in a rounded-off corner
+        harness.event(Event::MouseDown(left_mouse((155., 155.))));
+        assert_eq!(clicks.get(), 0);
+
+        harness.event(Event::MouseDown(left_mouse((200., 200.))));
+        assert_eq!(clicks.get(), 1);
+    });
+}
+
+#[test]
+fn transform_maps_mouse_into_child_space() {
+    let clicked_at: Rc<Cell<Option<Point>>> = Default::default();
+    let clicked_clone = clicked_at.clone();
+    let child = ModularWidget::new(())
+        .event_fn(move |_, _ctx, event, _data, _env| {
+            if let Event::MouseDown(mouse) = event {
+                clicked_clone.set(Some(mouse.pos));
+            }
+        })
+        .layout_fn(|_, _ctx, bc, _data, _env| bc.max());
+    // at (150, 150) in the window, the child is painted at half size, from
+    // (175, 175) to (225, 225).
+    let widget = Transform::new(child.fix_size(100., 100.), Affine::default())
+        .scale(0.5, 0.5)
+        .center();
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        harness.event(Event::MouseDown(left_mouse((160., 160.))));
+        assert_eq!(clicked_at.get(), None);
+
+        harness.event(Event::MouseDown(left_mouse((180., 180.))));
+        assert_eq!(clicked_at.get(), Some(Point::new(10., 10.)));
+    });
+}
+
+/// `Transform` paints the bounding box of its transformed child, and updates
+/// it when the transform changes.
+#[test]
+fn transform_paint_rect_follows_transform() {
+    const ROTATE: Selector = Selector::new("druid-tests.rotate");
+    let paint_rect = Rc::new(Cell::new(Rect::ZERO));
+    let paint_rect_2 = paint_rect.clone();
+    let child = WidgetPod::new(Transform::new(
+        SizedBox::empty().width(100.).height(20.),
+        Affine::default(),
+    ));
+    let widget = ModularWidget::new(child)
+        .event_fn(|child, ctx, event, data, env| {
+            if matches!(event, Event::Command(cmd) if cmd.is(ROTATE)) {
+                let quarter_turn = Affine::rotate(std::f64::consts::FRAC_PI_2);
+                child.widget_mut().set_transform(quarter_turn);
+                ctx.request_paint();
+            }
+            child.event(ctx, event, data, env);
+        })
+        .lifecycle_fn(|child, ctx, event, data, env| child.lifecycle(ctx, event, data, env))
+        .layout_fn(move |child, ctx, bc, data, env| {
+            child.layout(ctx, &bc.loosen(), data, env);
+            child.set_origin(ctx, data, env, Point::ORIGIN);
+            paint_rect_2.set(child.paint_rect());
+            bc.max()
+        });
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(paint_rect.get(), Rect::new(0., 0., 100., 20.));
+
+        // only paint was requested, but the new transform needs a layout
+        harness.submit_command(ROTATE);
+        harness.just_layout();
+        // the rotated child reaches above and below our layout rect
+        let rotated = paint_rect.get();
+        assert!((rotated.y0 + 40.).abs() < 1e-9 && (rotated.y1 - 60.).abs() < 1e-9);
+        assert_eq!((rotated.x0, rotated.x1), (0., 100.));
+    });
+}
+
+#[test]
+fn tabs_arrow_keys_change_selection() {
+    let clicks: Vec<Rc<Cell<usize>>> = (0..3).map(|_| Default::default()).collect();
+    let body = |count: Rc<Cell<usize>>| {
+        ModularWidget::new(())
+            .event_fn(move |_, _ctx, event, _data, _env| {
+                if let Event::MouseDown(_) = event {
+                    count.set(count.get() + 1);
+                }
+            })
+            .layout_fn(|_, _ctx, bc, _data, _env| bc.max())
+    };
+    let tabs = Tabs::new()
+        .with_tab("zero", body(clicks[0].clone()))
+        .with_tab("one", body(clicks[1].clone()))
+        .with_tab("two", body(clicks[2].clone()));
+    let counts = || clicks.iter().map(|c| c.get()).collect::<Vec<_>>();
+
+    Harness::create_simple((), tabs, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        let right = KeyEvent::for_test(Modifiers::default(), KbKey::ArrowRight);
+        let left = KeyEvent::for_test(Modifiers::default(), KbKey::ArrowLeft);
+
+        // clicking the tab bar focuses it
+        harness.event(Event::MouseDown(left_mouse((5., 5.))));
+        harness.event(Event::MouseUp(left_mouse((5., 5.))));
+
+        harness.event(Event::KeyDown(right.clone()));
+        harness.event(Event::MouseDown(left_mouse((200., 300.))));
+        assert_eq!(counts(), vec![0, 1, 0]);
+
+        // selection stops at the last tab
+        harness.event(Event::KeyDown(right.clone()));
+        harness.event(Event::KeyDown(right));
+        harness.event(Event::MouseDown(left_mouse((200., 300.))));
+        assert_eq!(counts(), vec![0, 1, 1]);
+
+        harness.event(Event::KeyDown(left));
+        harness.event(Event::MouseDown(left_mouse((200., 300.))));
+        assert_eq!(counts(), vec![0, 2, 1]);
+    });
+}
+
+#[test]
+fn switch_toggles_and_settles() {
+    let widget = Switch::new().center().env_scope(|env, _| {
+        env.set(theme::SWITCH_TOGGLE_DURATION, 0u64);
+    });
+
+    Harness::create_simple(false, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        harness.event(Event::MouseDown(left_mouse((200., 200.))));
+        harness.event(Event::MouseUp(left_mouse((200., 200.))));
+        assert!(*harness.data());
+        assert!(harness.window().wants_animation_frame());
+
+        // with no toggle duration, the knob gets there in a single frame
+        harness.animate();
+        assert!(!harness.window().wants_animation_frame());
+    });
+}
+
+#[test]
+fn switch_snaps_with_reduced_motion() {
+    let widget = Switch::new().center().env_scope(|env, _| {
+        env.set(theme::REDUCED_MOTION, true);
+    });
+
+    Harness::create_simple(false, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        harness.event(Event::MouseDown(left_mouse((200., 200.))));
+        harness.event(Event::MouseUp(left_mouse((200., 200.))));
+        assert!(*harness.data());
+        assert!(!harness.window().wants_animation_frame());
+    });
+}
+
+#[test]
+fn stepper_steps_within_range() {
+    // the stepper is stretched to fill the window; the top half increases
+    let widget = Stepper::new().with_range(0., 2.).with_step(1.5);
+    let click = |harness: &mut Harness<f64>, y: f64| {
+        harness.event(Event::MouseDown(left_mouse((10., y))));
+        harness.event(Event::MouseUp(left_mouse((10., y))));
+    };
+
+    Harness::create_simple(0.0, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        click(harness, 100.);
+        assert_eq!(*harness.data(), 1.5);
+        click(harness, 100.);
+        assert_eq!(*harness.data(), 2.0);
+        click(harness, 300.);
+        assert_eq!(*harness.data(), 0.5);
+        click(harness, 300.);
+        assert_eq!(*harness.data(), 0.0);
+    });
+}
+
+#[test]
+fn parse_reports_invalid_text() {
+    let validity = Rc::new(Cell::new(None));
+    let validity2 = validity.clone();
+    let input = data_setter::<String>()
+        .paint_fn(move |_, _, _, env| validity2.set(Some(env.get(PARSE_IS_VALID))));
+
+    Harness::create_simple(None::<u32>, Parse::new(input), |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        harness.paint();
+        assert_eq!(validity.get(), Some(true));
+
+        harness.submit_command(set_data("12".to_string()));
+        harness.paint_invalid();
+        assert_eq!(*harness.data(), Some(12));
+        assert_eq!(validity.get(), Some(true));
+
+        harness.submit_command(set_data("12x".to_string()));
+        assert!(!harness.window().invalid().is_empty());
+        harness.paint_invalid();
+        assert_eq!(*harness.data(), None);
+        assert_eq!(validity.get(), Some(false));
+
+        harness.submit_command(set_data("".to_string()));
+        harness.paint_invalid();
+        assert_eq!(validity.get(), Some(true));
+    });
+}
+
+#[test]
+fn parse_with_custom_functions() {
+    let shown = Rc::new(RefCell::new(String::new()));
+    let shown2 = shown.clone();
+    let input =
+        data_setter::<String>().paint_fn(move |_, _, data, _| *shown2.borrow_mut() = data.clone());
+    let price = Parse::with(
+        input,
+        |s: &str| s.trim_start_matches('$').parse::<f64>(),
+        |val: &f64| format!("${:.2}", val),
+    );
+
+    Harness::create_simple(Some(1.5), price, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        harness.paint();
+        assert_eq!(*shown.borrow(), "$1.50");
+
+        harness.submit_command(set_data("$2".to_string()));
+        assert_eq!(*harness.data(), Some(2.0));
+    });
+}
+
+#[test]
+fn mouse_events_are_offset_into_child() {
+    let positions = Rc::new(RefCell::new(Vec::new()));
+    let positions2 = positions.clone();
+    let inner = ModularWidget::new(())
+        .event_fn(move |_, ctx, event, _, _| match event {
+            Event::MouseDown(mouse) => {
+                ctx.set_active(true);
+                positions2.borrow_mut().push(mouse.pos);
+            }
+            Event::MouseMove(mouse) | Event::MouseUp(mouse) => {
+                ctx.set_active(false);
+                positions2.borrow_mut().push(mouse.pos);
+            }
+            _ => (),
+        })
+        .layout_fn(|_, _, _, _, _| Size::new(100., 100.));
+    let widget = ModularWidget::new(WidgetPod::new(inner))
+        .event_fn(|child, ctx, event, data, env| child.event(ctx, event, data, env))
+        .lifecycle_fn(|child, ctx, event, data, env| child.lifecycle(ctx, event, data, env))
+        .layout_fn(|child, ctx, bc, data, env| {
+            child.layout(ctx, bc, data, env);
+            child.set_origin(ctx, data, env, Point::new(50., 50.));
+            bc.max()
+        });
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        // outside the child: nothing is delivered
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        assert!(positions.borrow().is_empty());
+
+        harness.event(Event::MouseDown(left_mouse((60., 70.))));
+        assert_eq!(*positions.borrow(), vec![Point::new(10., 20.)]);
+
+        // the child is active, so it still gets the mouse outside its rect
+        harness.event(Event::MouseMove(move_mouse((20., 30.))));
+        assert_eq!(positions.borrow()[1], Point::new(-30., -20.));
+
+        // but not once it has let go
+        harness.event(Event::MouseUp(left_mouse((20., 30.))));
+        assert_eq!(positions.borrow().len(), 2);
+    });
+}
+
+#[test]
+fn handled_click_skips_lower_layers() {
+    let clicks = Rc::new(Cell::new((0, 0)));
+    let (bottom, top) = (clicks.clone(), clicks.clone());
+    let widget = ZStack::new()
+        .with_child(
+            SizedBox::empty()
+                .expand()
+                .on_click(move |_, _, _| bottom.set((bottom.get().0 + 1, bottom.get().1))),
+        )
+        .with_child(
+            Button::new("top")
+                .on_click(move |_, _, _| top.set((top.get().0, top.get().1 + 1)))
+                .center(),
+        );
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        // the button is centered, the corner only has the background
+        harness.event(Event::MouseDown(left_mouse((200., 200.))));
+        harness.event(Event::MouseUp(left_mouse((200., 200.))));
+        assert_eq!(clicks.get(), (0, 1));
+
+        harness.event(Event::MouseDown(left_mouse((5., 5.))));
+        harness.event(Event::MouseUp(left_mouse((5., 5.))));
+        assert_eq!(clicks.get(), (1, 1));
+    });
+}
+
+/// `Click` marks the left mouse events it acts on as handled, and leaves
+/// everything else for the widgets around it.
+#[test]
+fn click_handles_left_mouse_events() {
+    let handled = Rc::new(RefCell::new(Vec::new()));
+    let handled_2 = handled.clone();
+    let child = WidgetPod::new(SizedBox::empty().expand().on_click(|_, _, _| ()));
+    let widget = ModularWidget::new(child)
+        .event_fn(move |child, ctx, event, data, env| {
+            child.event(ctx, event, data, env);
+            handled_2.borrow_mut().push(ctx.is_handled());
+        })
+        .lifecycle_fn(|child, ctx, event, data, env| child.lifecycle(ctx, event, data, env))
+        .layout_fn(|child, ctx, bc, data, env| {
+            let size = child.layout(ctx, bc, data, env);
+            child.set_origin(ctx, data, env, Point::ORIGIN);
+            size
+        });
+
+    Harness::create_simple((), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        handled.borrow_mut().clear();
+
+        let mut right = left_mouse((10., 10.));
+        right.button = MouseButton::Right;
+        harness.event(Event::MouseMove(move_mouse((10., 10.))));
+        harness.event(Event::MouseDown(right.clone()));
+        harness.event(Event::MouseUp(right));
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(*handled.borrow(), vec![false, false, false, true, true]);
+    });
+}
+
+#[test]
+fn checkbox_stays_active_while_dragged_out() {
+    let id = WidgetId::next();
+    let widget = Checkbox::new("check").with_id(id);
+
+    Harness::create_simple(false, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        let rect = harness.get_state(id).layout_rect();
+        let inside = rect.center();
+        let outside = Point::new(rect.x1 + 10., rect.y1 + 10.);
+
+        harness.event(Event::MouseMove(move_mouse(inside)));
+        harness.event(Event::MouseDown(left_mouse(inside)));
+        let state = harness.get_state(id);
+        assert!(state.is_hot && state.is_active);
+
+        harness.event(Event::MouseMove(move_mouse(outside)));
+        let state = harness.get_state(id);
+        assert!(!state.is_hot && state.is_active);
+
+        // releasing outside doesn't toggle the checkbox
+        harness.event(Event::MouseUp(left_mouse(outside)));
+        assert!(!harness.get_state(id).is_active);
+        assert!(!*harness.data());
+    });
+}
+
+#[test]
+fn slider_drag_continues_outside() {
+    let id = WidgetId::next();
+    let widget = Slider::new().with_id(id).fix_width(200.).center();
+
+    Harness::create_simple(0.0, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        let start = Point::new(150., 200.);
+        assert!(harness.get_state(id).layout_rect().contains(start));
+        harness.event(Event::MouseMove(move_mouse(start)));
+        harness.event(Event::MouseDown(left_mouse(start)));
+
+        // dragging far past the end, and well below the slider
+        harness.event(Event::MouseMove(move_mouse((380., 380.))));
+        assert_eq!(*harness.data(), 1.0);
+        assert!(!harness.get_state(id).is_hot);
+        harness.event(Event::MouseMove(move_mouse((5., 380.))));
+        assert_eq!(*harness.data(), 0.0);
+
+        harness.event(Event::MouseUp(left_mouse((5., 380.))));
+        assert!(!harness.get_state(id).is_active);
+    });
+}
+
+#[test]
+fn range_slider_knobs_do_not_cross() {
+    // the slider spans 100..300, and the knobs travel from 109 to 291
+    let widget = RangeSlider::new().fix_width(200.).center();
+
+    Harness::create_simple((0.25, 0.75), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+
+        // drag the end knob past the start
+        harness.event(Event::MouseDown(left_mouse((245.5, 200.))));
+        harness.event(Event::MouseMove(move_mouse((100., 200.))));
+        assert_eq!(*harness.data(), (0.25, 0.25));
+        harness.event(Event::MouseUp(left_mouse((100., 200.))));
+        assert_eq!(*harness.data(), (0.25, 0.25));
+
+        // with both knobs in the same place, dragging left moves the start
+        harness.event(Event::MouseDown(left_mouse((154.5, 200.))));
+        harness.event(Event::MouseMove(move_mouse((100., 200.))));
+        harness.event(Event::MouseUp(left_mouse((100., 200.))));
+        assert_eq!(*harness.data(), (0.0, 0.25));
+    });
+}
+
+#[test]
+fn focused_textbox_scrolls_into_view() {
+    const FOCUS_SECOND: Selector = Selector::new("druid-tests.focus-second");
+    let (content, second) = widget_id2();
+
+    let focus_second = ModularWidget::new(()).event_fn(move |_, ctx, event, _, _| {
+        if let Event::Command(cmd) = event {
+            if cmd.is(FOCUS_SECOND) {
+                ctx.set_focus(second);
+            }
+        }
+    });
+    let widget = Scroll::new(
+        Flex::column()
+            .with_child(focus_second)
+            .with_spacer(1000.)
+            .with_child(TextBox::new().with_id(second))
+            .with_id(content),
+    )
+    .vertical();
+
+    Harness::create_simple(String::new(), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert_eq!(harness.get_state(content).viewport_offset, Vec2::ZERO);
+
+        harness.submit_command(FOCUS_SECOND);
+        assert_eq!(harness.window().focus, Some(second));
+
+        // the textbox is now at the bottom of the viewport
+        let offset = harness.get_state(content).viewport_offset;
+        let textbox = harness.get_state(second).layout_rect();
+        assert_eq!(textbox.y1 - offset.y, 400.);
+    });
+}
+
+#[test]
+fn focus_ring_only_for_keyboard_focus() {
+    const FOCUS: Selector = Selector::new("druid-tests.focus");
+    let textbox = WidgetId::next();
+    let showing_ring: Rc<Cell<bool>> = Default::default();
+
+    let make_widget = |showing_ring: Rc<Cell<bool>>| {
+        let ring = TextBox::new().with_id(textbox).focus_ring();
+        ModularWidget::new(WidgetPod::new(ring))
+            .event_fn(move |child, ctx, event, data, env| {
+                if matches!(event, Event::Command(cmd) if cmd.is(FOCUS)) {
+                    ctx.set_focus(textbox);
+                }
+                child.event(ctx, event, data, env);
+                showing_ring.set(child.widget().is_showing_ring());
+            })
+            .lifecycle_fn(|child, ctx, event, data, env| child.lifecycle(ctx, event, data, env))
+            .update_fn(|child, ctx, _old_data, data, env| child.update(ctx, data, env))
+            .layout_fn(|child, ctx, bc, data, env| {
+                let size = child.layout(ctx, bc, data, env);
+                child.set_origin(ctx, data, env, Point::ORIGIN);
+                size
+            })
+    };
+
+    // focus that doesn't come from the mouse, like the tab key
+    Harness::create_simple(
+        String::new(),
+        make_widget(showing_ring.clone()),
+        |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.submit_command(FOCUS);
+            assert_eq!(harness.window().focus, Some(textbox));
+            harness.submit_command(Selector::NOOP);
+            assert!(showing_ring.get());
+        },
+    );
+
+    Harness::create_simple(
+        String::new(),
+        make_widget(showing_ring.clone()),
+        |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            harness.event(Event::MouseDown(left_mouse((10., 10.))));
+            harness.event(Event::MouseUp(left_mouse((10., 10.))));
+            assert_eq!(harness.window().focus, Some(textbox));
+            assert!(!showing_ring.get());
+        },
+    );
+}
+
+#[test]
+fn scroll_offset_in_data() {
+    let content = WidgetId::next();
+    let widget = Scroll::new(SizedBox::empty().height(1000.).with_id(content))
+        .vertical()
+        .offset_lens(lens::Identity);
+
+    Harness::create_simple(Vec2::new(0., 300.), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        // restored from the data
+        assert_eq!(
+            harness.get_state(content).viewport_offset,
+            Vec2::new(0., 300.)
+        );
+
+        // and written back to it
+        harness.event(Event::Wheel(scroll_mouse((10., 10.), (0., 100.))));
+        assert_eq!(*harness.data(), Vec2::new(0., 400.));
+
+        // scrolling past the end is clamped
+        harness.event(Event::Wheel(scroll_mouse((10., 10.), (0., 1000.))));
+        assert_eq!(*harness.data(), Vec2::new(0., 600.));
+    });
+}
+
+#[test]
+fn multiline_textbox_keeps_cursor_in_view() {
+    let textbox = WidgetId::next();
+    let widget = Scroll::new(TextBox::multiline().with_id(textbox)).vertical();
+    let lines = "line\n".repeat(40);
+
+    Harness::create_simple(lines, widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        assert!(harness.get_state(textbox).layout_rect().height() > 400.);
+
+        harness.event(Event::MouseDown(left_mouse((10., 10.))));
+        harness.event(Event::MouseUp(left_mouse((10., 10.))));
+        assert_eq!(harness.get_state(textbox).viewport_offset, Vec2::ZERO);
+
+        let down = KeyEvent::for_test(Modifiers::default(), KbKey::ArrowDown);
+        for _ in 0..40 {
+            harness.event(Event::KeyDown(down.clone()));
+        }
+        // the last scroll request was submitted during update, so it is only
+        // delivered along with the next event.
+        harness.submit_command(Selector::NOOP);
+        assert!(harness.get_state(textbox).viewport_offset.y > 0.);
+    });
+}
+
+/// The clipboard shortcuts in a `TextBox` act on the selection, and don't
+/// type anything.
+///
+/// There is no running application, and so no clipboard, in these tests;
+/// a cut still removes the selection, and a paste does nothing.
+#[test]
+fn textbox_clipboard_shortcuts() {
+    let cmd = |key| Event::KeyDown(KeyEvent::for_test(RawMods::from(SysMods::Cmd), key));
+    let select_left = KeyEvent::for_test(RawMods::Shift, KbKey::ArrowLeft);
+    let widget = TextBox::new().center();
+
+    Harness::create_simple("hello".to_string(), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        harness.event(Event::MouseDown(left_mouse((200., 200.))));
+        harness.event(Event::MouseUp(left_mouse((200., 200.))));
+        harness.event(Event::KeyDown(KeyEvent::for_test(
+            RawMods::None,
+            KbKey::End,
+        )));
+
+        harness.event(Event::KeyDown(select_left.clone()));
+        harness.event(Event::KeyDown(select_left.clone()));
+        harness.event(cmd("c"));
+        assert_eq!(harness.data(), "hello");
+
+        harness.event(cmd("x"));
+        assert_eq!(harness.data(), "hel");
+
+        harness.event(cmd("v"));
+        assert_eq!(harness.data(), "hel");
+
+        harness.event(cmd("a"));
+        harness.event(cmd("x"));
+        assert_eq!(harness.data(), "");
+    });
+}
+
+#[test]
+fn hotkey_submits_command_instead_of_typing() {
+    const SAVE: Selector = Selector::new("druid-tests.save");
+    let saves: Rc<Cell<usize>> = Default::default();
+
+    let saves_ = saves.clone();
+    let counter = ModularWidget::new(())
+        .event_fn(move |_, _, event, _, _| {
+            if let Event::Command(cmd) = event {
+                if cmd.is(SAVE) {
+                    saves_.set(saves_.get() + 1);
+                }
+            }
+        })
+        .layout_fn(|_, _, _, _, _| Size::ZERO);
+    let widget = Flex::column()
+        .with_child(TextBox::new())
+        .with_child(counter)
+        .on_hotkey(HotKey::new(RawMods::Ctrl, "s"), SAVE)
+        .center();
+
+    Harness::create_simple(String::new(), widget, |harness| {
+        harness.send_initial_events();
+        harness.just_layout();
+        // the text box is in the center, and is the only thing that takes focus
+        harness.event(Event::MouseDown(left_mouse((200., 200.))));
+
+        harness.event(Event::KeyDown(KeyEvent::for_test(RawMods::Ctrl, "s")));
+        assert_eq!(saves.get(), 1);
+        assert_eq!(harness.data(), "");
+
+        harness.event(Event::KeyDown(KeyEvent::for_test(RawMods::None, "s")));
+        assert_eq!(saves.get(), 1);
+        assert_eq!(harness.data(), "s");
+    });
+}
@@ -20,7 +20,7 @@ use super::{
 };
 use crate::kurbo::Line;
 use crate::piet::PietText;
-use crate::{Application, Env, MouseEvent, PaintCtx, Point, Rect, UpdateCtx};
+use crate::{Application, Clipboard, Env, MouseEvent, PaintCtx, Point, Rect, UpdateCtx};
 
 /// A component for widgets that offer text editing.
 ///
@@ -132,15 +132,25 @@ impl<T: TextStorage + EditableText> Editor<T> {
 
     /// Handle a copy command
     pub fn copy(&self, data: &mut T) {
+        self.copy_to(&mut Application::global().clipboard(), data)
+    }
+
+    /// Handle a copy command, putting the selected text on `clipboard`.
+    pub fn copy_to(&self, clipboard: &mut Clipboard, data: &mut T) {
         if !self.data_is_stale(data) {
-            self.set_clipboard()
+            self.set_clipboard(clipboard)
         }
     }
 
     /// Handle a cut command
     pub fn cut(&mut self, data: &mut T) {
+        self.cut_to(&mut Application::global().clipboard(), data)
+    }
+
+    /// Handle a cut command, moving the selected text to `clipboard`.
+    pub fn cut_to(&mut self, clipboard: &mut Clipboard, data: &mut T) {
         if !self.data_is_stale(data) {
-            self.set_clipboard();
+            self.set_clipboard(clipboard);
             self.delete_backward(data);
         }
     }
@@ -279,14 +289,14 @@ impl<T: TextStorage + EditableText> Editor<T> {
         self.selection = Selection::caret(self.selection.min());
     }
 
-    fn set_clipboard(&self) {
+    fn set_clipboard(&self, clipboard: &mut Clipboard) {
         if let Some(text) = self
             .layout
             .text()
             .and_then(|txt| txt.slice(self.selection.range()))
         {
             if !text.is_empty() {
-                Application::global().clipboard().put_string(text);
+                clipboard.put_string(text);
             }
         }
     }
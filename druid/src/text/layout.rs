@@ -178,6 +178,20 @@ impl<T: TextStorage> TextLayout<T> {
         }
     }
 
+    /// A new layout for `text`, with the same font, color and alignment as
+    /// this one, but no wrap width.
+    pub(crate) fn restyled<U>(&self, text: U) -> TextLayout<U> {
+        TextLayout {
+            text: Some(text),
+            font: self.font.clone(),
+            text_size_override: self.text_size_override.clone(),
+            text_color: self.text_color.clone(),
+            layout: None,
+            wrap_width: f64::INFINITY,
+            alignment: self.alignment,
+        }
+    }
+
     /// Returns the [`TextStorage`] backing this layout, if it exists.
     ///
     /// [`TextStorage`]: trait.TextStorage.html
@@ -13,12 +13,47 @@
 // limitations under the License.
 
 //! Theme keys and initial values.
+//!
+//! The built-in widgets read their colors and sizes from the [`Env`], using
+//! the keys defined here. Every [`Env`] starts out with a default value for
+//! each of them, which can be changed for the whole application with
+//! [`AppLauncher::configure_env`], or for just a part of the widget tree
+//! with [`WidgetExt::env_scope`]:
+//!
+//! ```
+//! use druid::widget::{Button, Flex, Label};
+//! use druid::{theme, Color, Widget, WidgetExt};
+//!
+//! fn ui() -> impl Widget<()> {
+//!     let warning = Flex::column()
+//!         .with_child(Label::new("Are you sure?"))
+//!         .with_child(Button::new("Delete"))
+//!         .env_scope(|env, _| {
+//!             env.set(theme::LABEL_COLOR, Color::rgb8(0xff, 0x80, 0x80));
+//!             env.set(theme::BUTTON_LIGHT, Color::rgb8(0x80, 0x20, 0x20));
+//!             env.set(theme::BUTTON_DARK, Color::rgb8(0x60, 0x10, 0x10));
+//!         });
+//!
+//!     Flex::column()
+//!         .with_child(Label::new("Regular text"))
+//!         .with_child(warning)
+//! }
+//! ```
+//!
+//! The colors can also be switched between a dark and a light palette with
+//! [`set_mode`]; see [`Mode`] for details.
+//!
+//! [`Env`]: ../struct.Env.html
+//! [`set_mode`]: fn.set_mode.html
+//! [`Mode`]: enum.Mode.html
+//! [`AppLauncher::configure_env`]: ../struct.AppLauncher.html#method.configure_env
+//! [`WidgetExt::env_scope`]: ../trait.WidgetExt.html#method.env_scope
 
 #![allow(missing_docs)]
 
 use crate::piet::Color;
 
-use crate::{Env, FontDescriptor, FontFamily, FontStyle, FontWeight, Insets, Key};
+use crate::{Data, Env, FontDescriptor, FontFamily, FontStyle, FontWeight, Insets, Key};
 
 pub const WINDOW_BACKGROUND_COLOR: Key<Color> =
     Key::new("org.linebender.druid.theme.window_background_color");
@@ -83,6 +118,72 @@ pub const WIDGET_PADDING_VERTICAL: Key<f64> =
 pub const WIDGET_CONTROL_COMPONENT_PADDING: Key<f64> =
     Key::new("org.linebender.druid.theme.widget-padding-control-label");
 
+/// The thickness of the track of a [`Slider`].
+///
+/// [`Slider`]: ../widget/struct.Slider.html
+pub const SLIDER_TRACK_THICKNESS: Key<f64> =
+    Key::new("org.linebender.druid.theme.slider_track_thickness");
+
+/// How strongly a disabled widget is dimmed, from `0.0` (not at all) to
+/// `1.0` (hidden behind the window background).
+///
+/// See [`WidgetExt::disabled_if`].
+///
+/// [`WidgetExt::disabled_if`]: ../trait.WidgetExt.html#method.disabled_if
+pub const DISABLED_DIM_FACTOR: Key<f64> =
+    Key::new("org.linebender.druid.theme.disabled_dim_factor");
+
+/// The time in milliseconds the mouse has to rest over a widget before its
+/// tooltip is shown.
+///
+/// See [`WidgetExt::tooltip`].
+///
+/// [`WidgetExt::tooltip`]: ../trait.WidgetExt.html#method.tooltip
+pub const TOOLTIP_DELAY: Key<u64> = Key::new("org.linebender.druid.theme.tooltip_delay");
+
+/// The time in milliseconds it takes a [`Switch`] knob to slide from one
+/// side to the other.
+///
+/// [`Switch`]: ../widget/struct.Switch.html
+pub const SWITCH_TOGGLE_DURATION: Key<u64> =
+    Key::new("org.linebender.druid.theme.switch_toggle_duration");
+
+/// The color of the ring painted around a widget that was focused with the
+/// keyboard.
+///
+/// See [`WidgetExt::focus_ring`].
+///
+/// [`WidgetExt::focus_ring`]: ../trait.WidgetExt.html#method.focus_ring
+pub const FOCUS_RING_COLOR: Key<Color> = Key::new("org.linebender.druid.theme.focus_ring_color");
+
+/// The width of the ring painted around a widget that was focused with the
+/// keyboard.
+///
+/// See [`WidgetExt::focus_ring`].
+///
+/// [`WidgetExt::focus_ring`]: ../trait.WidgetExt.html#method.focus_ring
+pub const FOCUS_RING_WIDTH: Key<f64> = Key::new("org.linebender.druid.theme.focus_ring_width");
+
+/// Whether widgets should avoid animating.
+///
+/// When this is `true`, animated widgets such as [`Switch`] and [`Spinner`]
+/// jump straight to their final state. It is `false` by default.
+///
+/// [`Switch`]: ../widget/struct.Switch.html
+/// [`Spinner`]: ../widget/struct.Spinner.html
+pub const REDUCED_MOTION: Key<bool> = Key::new("org.linebender.druid.theme.reduced_motion");
+
+/// Whether widgets should be drawn with more contrast.
+///
+/// When this is `true`, bordered widgets such as [`Button`] and [`TextBox`]
+/// draw their borders twice as wide, in [`LABEL_COLOR`] instead of the
+/// subtler border colors. It is `false` by default.
+///
+/// [`Button`]: ../widget/struct.Button.html
+/// [`TextBox`]: ../widget/struct.TextBox.html
+/// [`LABEL_COLOR`]: constant.LABEL_COLOR.html
+pub const HIGH_CONTRAST: Key<bool> = Key::new("org.linebender.druid.theme.high_contrast");
+
 pub const SCROLLBAR_COLOR: Key<Color> = Key::new("org.linebender.druid.theme.scrollbar_color");
 pub const SCROLLBAR_BORDER_COLOR: Key<Color> =
     Key::new("org.linebender.druid.theme.scrollbar_border_color");
@@ -96,27 +197,129 @@ pub const SCROLLBAR_RADIUS: Key<f64> = Key::new("org.linebender.druid.theme.scro
 pub const SCROLLBAR_EDGE_WIDTH: Key<f64> =
     Key::new("org.linebender.druid.theme.scrollbar_edge_width");
 
+/// The color palettes available through [`set_mode`].
+///
+/// [`set_mode`]: fn.set_mode.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum Mode {
+    /// Light text on a dark background. This is the default.
+    Dark,
+    /// Dark text on a light background.
+    Light,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::Dark
+    }
+}
+
+/// Set all of the theme colors in `env` to the palette for `mode`.
+///
+/// Only the colors that differ between modes are changed; sizes, fonts and
+/// the accent colors ([`PRIMARY_LIGHT`], [`PRIMARY_DARK`] and
+/// [`SELECTION_COLOR`]) are left alone, so they can be customized
+/// independently.
+///
+/// This can be used with [`AppLauncher::configure_env`] to pick a mode for
+/// the whole application, or with [`WidgetExt::env_scope`] to use a different
+/// mode in part of the UI. If the mode depends on the data, the widgets in
+/// the scope are repainted when it changes:
+///
+/// ```
+/// use druid::widget::{Flex, Label};
+/// use druid::{theme, Widget, WidgetExt};
+///
+/// fn preview() -> impl Widget<bool> {
+///     Flex::column()
+///         .with_child(Label::new("Preview"))
+///         .env_scope(|env, dark: &bool| {
+///             let mode = if *dark { theme::Mode::Dark } else { theme::Mode::Light };
+///             theme::set_mode(env, mode);
+///         })
+/// }
+/// ```
+///
+/// [`PRIMARY_LIGHT`]: constant.PRIMARY_LIGHT.html
+/// [`PRIMARY_DARK`]: constant.PRIMARY_DARK.html
+/// [`SELECTION_COLOR`]: constant.SELECTION_COLOR.html
+/// [`AppLauncher::configure_env`]: ../struct.AppLauncher.html#method.configure_env
+/// [`WidgetExt::env_scope`]: ../trait.WidgetExt.html#method.env_scope
+pub fn set_mode(env: &mut Env, mode: Mode) {
+    match mode {
+        Mode::Dark => {
+            env.set(WINDOW_BACKGROUND_COLOR, Color::rgb8(0x29, 0x29, 0x29));
+            env.set(LABEL_COLOR, Color::rgb8(0xf0, 0xf0, 0xea));
+            env.set(PLACEHOLDER_COLOR, Color::rgb8(0x80, 0x80, 0x80));
+            env.set(BACKGROUND_LIGHT, Color::rgb8(0x3a, 0x3a, 0x3a));
+            env.set(BACKGROUND_DARK, Color::rgb8(0x31, 0x31, 0x31));
+            env.set(FOREGROUND_LIGHT, Color::rgb8(0xf9, 0xf9, 0xf9));
+            env.set(FOREGROUND_DARK, Color::rgb8(0xbf, 0xbf, 0xbf));
+            env.set(BUTTON_DARK, Color::BLACK);
+            env.set(BUTTON_LIGHT, Color::rgb8(0x21, 0x21, 0x21));
+            env.set(BORDER_DARK, Color::rgb8(0x3a, 0x3a, 0x3a));
+            env.set(BORDER_LIGHT, Color::rgb8(0xa1, 0xa1, 0xa1));
+            env.set(SELECTION_TEXT_COLOR, Color::rgb8(0x00, 0x00, 0x00));
+            env.set(CURSOR_COLOR, Color::WHITE);
+            env.set(SCROLLBAR_COLOR, Color::rgb8(0xff, 0xff, 0xff));
+            env.set(SCROLLBAR_BORDER_COLOR, Color::rgb8(0x77, 0x77, 0x77));
+        }
+        Mode::Light => {
+            env.set(WINDOW_BACKGROUND_COLOR, Color::rgb8(0xf2, 0xf2, 0xf2));
+            env.set(LABEL_COLOR, Color::rgb8(0x1e, 0x1e, 0x1e));
+            env.set(PLACEHOLDER_COLOR, Color::rgb8(0x80, 0x80, 0x80));
+            env.set(BACKGROUND_LIGHT, Color::rgb8(0xff, 0xff, 0xff));
+            env.set(BACKGROUND_DARK, Color::rgb8(0xe4, 0xe4, 0xe4));
+            env.set(FOREGROUND_LIGHT, Color::rgb8(0xf9, 0xf9, 0xf9));
+            env.set(FOREGROUND_DARK, Color::rgb8(0xbf, 0xbf, 0xbf));
+            env.set(BUTTON_DARK, Color::rgb8(0xd0, 0xd0, 0xd0));
+            env.set(BUTTON_LIGHT, Color::rgb8(0xf8, 0xf8, 0xf8));
+            env.set(BORDER_DARK, Color::rgb8(0xa1, 0xa1, 0xa1));
+            env.set(BORDER_LIGHT, Color::rgb8(0x5a, 0x5a, 0x5a));
+            env.set(SELECTION_TEXT_COLOR, Color::WHITE);
+            env.set(CURSOR_COLOR, Color::BLACK);
+            env.set(SCROLLBAR_COLOR, Color::rgb8(0x40, 0x40, 0x40));
+            env.set(SCROLLBAR_BORDER_COLOR, Color::rgb8(0xa0, 0xa0, 0xa0));
+        }
+    }
+}
+
+/// The border color of a control that isn't focused, which is lighter while
+/// the control is hot, or [`LABEL_COLOR`] with [`HIGH_CONTRAST`].
+///
+/// [`LABEL_COLOR`]: constant.LABEL_COLOR.html
+/// [`HIGH_CONTRAST`]: constant.HIGH_CONTRAST.html
+pub(crate) fn border_color(env: &Env, is_hot: bool) -> Color {
+    if env.get(HIGH_CONTRAST) {
+        env.get(LABEL_COLOR)
+    } else if is_hot {
+        env.get(BORDER_LIGHT)
+    } else {
+        env.get(BORDER_DARK)
+    }
+}
+
+/// The width of a border that is normally `width` wide, which is doubled
+/// with [`HIGH_CONTRAST`].
+///
+/// [`HIGH_CONTRAST`]: constant.HIGH_CONTRAST.html
+pub(crate) fn border_width(env: &Env, width: f64) -> f64 {
+    if env.get(HIGH_CONTRAST) {
+        width * 2.0
+    } else {
+        width
+    }
+}
+
 /// An initial theme.
 pub(crate) fn add_to_env(env: Env) -> Env {
-    env.adding(WINDOW_BACKGROUND_COLOR, Color::rgb8(0x29, 0x29, 0x29))
-        .adding(LABEL_COLOR, Color::rgb8(0xf0, 0xf0, 0xea))
-        .adding(PLACEHOLDER_COLOR, Color::rgb8(0x80, 0x80, 0x80))
+    let mut env = env
         .adding(PRIMARY_LIGHT, Color::rgb8(0x5c, 0xc4, 0xff))
         .adding(PRIMARY_DARK, Color::rgb8(0x00, 0x8d, 0xdd))
         .adding(PROGRESS_BAR_RADIUS, 4.)
-        .adding(BACKGROUND_LIGHT, Color::rgb8(0x3a, 0x3a, 0x3a))
-        .adding(BACKGROUND_DARK, Color::rgb8(0x31, 0x31, 0x31))
-        .adding(FOREGROUND_LIGHT, Color::rgb8(0xf9, 0xf9, 0xf9))
-        .adding(FOREGROUND_DARK, Color::rgb8(0xbf, 0xbf, 0xbf))
-        .adding(BUTTON_DARK, Color::BLACK)
-        .adding(BUTTON_LIGHT, Color::rgb8(0x21, 0x21, 0x21))
         .adding(BUTTON_BORDER_RADIUS, 4.)
         .adding(BUTTON_BORDER_WIDTH, 2.)
-        .adding(BORDER_DARK, Color::rgb8(0x3a, 0x3a, 0x3a))
-        .adding(BORDER_LIGHT, Color::rgb8(0xa1, 0xa1, 0xa1))
         .adding(SELECTION_COLOR, Color::rgb8(0xf3, 0x00, 0x21))
-        .adding(SELECTION_TEXT_COLOR, Color::rgb8(0x00, 0x00, 0x00))
-        .adding(CURSOR_COLOR, Color::WHITE)
         .adding(TEXT_SIZE_NORMAL, 15.0)
         .adding(TEXT_SIZE_LARGE, 24.0)
         .adding(BASIC_WIDGET_HEIGHT, 18.0)
@@ -125,8 +328,14 @@ pub(crate) fn add_to_env(env: Env) -> Env {
         .adding(TEXTBOX_BORDER_RADIUS, 2.)
         .adding(TEXTBOX_BORDER_WIDTH, 1.)
         .adding(TEXTBOX_INSETS, Insets::new(4.0, 2.0, 4.0, 2.0))
-        .adding(SCROLLBAR_COLOR, Color::rgb8(0xff, 0xff, 0xff))
-        .adding(SCROLLBAR_BORDER_COLOR, Color::rgb8(0x77, 0x77, 0x77))
+        .adding(SLIDER_TRACK_THICKNESS, 4.0)
+        .adding(DISABLED_DIM_FACTOR, 0.5)
+        .adding(TOOLTIP_DELAY, 500u64)
+        .adding(SWITCH_TOGGLE_DURATION, 200u64)
+        .adding(FOCUS_RING_COLOR, Color::rgb8(0x5c, 0xc4, 0xff))
+        .adding(FOCUS_RING_WIDTH, 2.)
+        .adding(REDUCED_MOTION, false)
+        .adding(HIGH_CONTRAST, false)
         .adding(SCROLLBAR_MAX_OPACITY, 0.7)
         .adding(SCROLLBAR_FADE_DELAY, 1500u64)
         .adding(SCROLLBAR_WIDTH, 8.)
@@ -151,7 +360,9 @@ pub(crate) fn add_to_env(env: Env) -> Env {
             FontDescriptor::new(FontFamily::SYSTEM_UI)
                 .with_style(FontStyle::Italic)
                 .with_size(15.0),
-        )
+        );
+    set_mode(&mut env, Mode::Dark);
+    env
 }
 
 #[deprecated(since = "0.7.0", note = "use Env::default() instead")]
@@ -18,6 +18,22 @@ use crate::widget::prelude::*;
 use crate::{Data, Rect, Size, UnitPoint, WidgetPod};
 
 /// A widget that aligns its child.
+///
+/// # Layout
+///
+/// By default an `Align` fills all the space it is given, and positions its
+/// child within that space. On an axis where its constraints are unbounded,
+/// such as the main axis of a [`Flex`] or the content of a [`Scroll`], there
+/// is no space to fill, so it takes the size of its child on that axis.
+///
+/// With [`shrink_to_fit`] it instead always takes the size of its child
+/// (within its constraints), which is what you want if it shouldn't claim
+/// the extra space for itself; the child is then only moved if the
+/// constraints force the `Align` to be bigger than it.
+///
+/// [`Flex`]: struct.Flex.html
+/// [`Scroll`]: struct.Scroll.html
+/// [`shrink_to_fit`]: #method.shrink_to_fit
 pub struct Align<T> {
     align: UnitPoint,
     child: WidgetPod<T, Box<dyn Widget<T>>>,
@@ -76,6 +92,20 @@ impl<T> Align<T> {
     }
 }
 
+impl<T> Align<T> {
+    /// Builder-style method to make this widget take the size of its child,
+    /// instead of filling the available space.
+    ///
+    /// See the [layout] section for details.
+    ///
+    /// [layout]: #layout
+    pub fn shrink_to_fit(mut self) -> Self {
+        self.width_factor = Some(1.0);
+        self.height_factor = Some(1.0);
+        self
+    }
+}
+
 impl<T: Data> Widget<T> for Align<T> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
         self.child.event(ctx, event, data, env)
@@ -134,6 +134,9 @@ impl<T: Data> Widget<T> for Button<T> {
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if ctx.env_key_changed(&theme::HIGH_CONTRAST) {
+            ctx.request_paint();
+        }
         self.label.update(ctx, old_data, data, env)
     }
 
@@ -158,7 +161,7 @@ impl<T: Data> Widget<T> for Button<T> {
         let is_active = ctx.is_active();
         let is_hot = ctx.is_hot();
         let size = ctx.size();
-        let stroke_width = env.get(theme::BUTTON_BORDER_WIDTH);
+        let stroke_width = theme::border_width(env, env.get(theme::BUTTON_BORDER_WIDTH));
 
         let rounded_rect = size
             .to_rect()
@@ -179,11 +182,7 @@ impl<T: Data> Widget<T> for Button<T> {
             )
         };
 
-        let border_color = if is_hot {
-            env.get(theme::BORDER_LIGHT)
-        } else {
-            env.get(theme::BORDER_DARK)
-        };
+        let border_color = theme::border_color(env, is_hot);
 
         ctx.stroke(rounded_rect, &border_color, stroke_width);
 
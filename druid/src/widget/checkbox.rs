@@ -16,10 +16,14 @@
 
 use crate::kurbo::{BezPath, Size};
 use crate::piet::{LineCap, LineJoin, LinearGradient, RenderContext, StrokeStyle, UnitPoint};
-use crate::theme;
 use crate::widget::{prelude::*, Label, LabelText};
+use crate::{theme, HotKey, KbKey, SysMods};
 
 /// A checkbox that toggles a `bool`.
+///
+/// The checkbox can be toggled by clicking either the box or its label. It
+/// also participates in keyboard focus, and can be toggled with the space bar
+/// while focused.
 pub struct Checkbox {
     child_label: Label<bool>,
 }
@@ -49,23 +53,30 @@ impl Widget<bool> for Checkbox {
                 if ctx.is_active() {
                     ctx.set_active(false);
                     if ctx.is_hot() {
-                        if *data {
-                            *data = false;
-                        } else {
-                            *data = true;
-                        }
+                        *data = !*data;
                     }
                     ctx.request_paint();
                 }
             }
+            Event::KeyDown(k_e) if ctx.is_focused() => match k_e {
+                k_e if HotKey::new(None, " ").matches(k_e) => {
+                    *data = !*data;
+                    ctx.set_handled();
+                }
+                k_e if HotKey::new(None, KbKey::Tab).matches(k_e) => ctx.focus_next(),
+                k_e if HotKey::new(SysMods::Shift, KbKey::Tab).matches(k_e) => ctx.focus_prev(),
+                _ => (),
+            },
             _ => (),
         }
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &bool, env: &Env) {
         self.child_label.lifecycle(ctx, event, data, env);
-        if let LifeCycle::HotChanged(_) = event {
-            ctx.request_paint();
+        match event {
+            LifeCycle::WidgetAdded => ctx.register_for_focus(),
+            LifeCycle::HotChanged(_) | LifeCycle::FocusChanged(_) => ctx.request_paint(),
+            _ => (),
         }
     }
 
@@ -93,39 +104,42 @@ impl Widget<bool> for Checkbox {
     fn paint(&mut self, ctx: &mut PaintCtx, data: &bool, env: &Env) {
         let size = env.get(theme::BASIC_WIDGET_HEIGHT);
         let x_padding = env.get(theme::WIDGET_CONTROL_COMPONENT_PADDING);
-        let border_width = 1.;
+        let border_width = theme::border_width(env, 1.);
 
         let rect = Size::new(size, size)
             .to_rect()
             .inset(-border_width / 2.)
             .to_rounded_rect(2.);
 
-        //Paint the background
-        let background_gradient = LinearGradient::new(
-            UnitPoint::TOP,
-            UnitPoint::BOTTOM,
-            (
-                env.get(theme::BACKGROUND_LIGHT),
-                env.get(theme::BACKGROUND_DARK),
-            ),
+        //Paint the background, flipping the gradient while pressed
+        let (top, bottom) = (
+            env.get(theme::BACKGROUND_LIGHT),
+            env.get(theme::BACKGROUND_DARK),
         );
+        let background_gradient = if ctx.is_active() {
+            LinearGradient::new(UnitPoint::TOP, UnitPoint::BOTTOM, (bottom, top))
+        } else {
+            LinearGradient::new(UnitPoint::TOP, UnitPoint::BOTTOM, (top, bottom))
+        };
 
         ctx.fill(rect, &background_gradient);
 
-        let border_color = if ctx.is_hot() {
-            env.get(theme::BORDER_LIGHT)
+        let border_color = if ctx.is_focused() {
+            env.get(theme::PRIMARY_LIGHT)
         } else {
-            env.get(theme::BORDER_DARK)
+            theme::border_color(env, ctx.is_hot())
         };
 
         ctx.stroke(rect, &border_color, border_width);
 
         if *data {
-            // Paint the checkmark
+            // Paint the checkmark; the path is designed for an 18px box, and
+            // is scaled to match the current check size.
+            let scale = size / 18.0;
             let mut path = BezPath::new();
-            path.move_to((4.0, 9.0));
-            path.line_to((8.0, 13.0));
-            path.line_to((14.0, 5.0));
+            path.move_to((4.0 * scale, 9.0 * scale));
+            path.line_to((8.0 * scale, 13.0 * scale));
+            path.line_to((14.0 * scale, 5.0 * scale));
 
             let style = StrokeStyle::new()
                 .line_cap(LineCap::Round)
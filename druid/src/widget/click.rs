@@ -30,10 +30,14 @@ use crate::{Data, Env, Event, EventCtx, LifeCycle, LifeCycleCtx, MouseButton, Wi
 /// mouse down, which can be useful for painting based on `ctx.is_active()`
 /// and `ctx.is_hot()`.
 ///
+/// Left clicks are marked as handled, so they don't also reach widgets
+/// underneath, like the lower layers of a [`ZStack`].
+///
 /// [`Controller`]: struct.Controller.html
 /// [`ControllerHost`]: struct.ControllerHost.html
 /// [`WidgetExt`]: ../trait.WidgetExt.html
 /// [`Button`]: struct.Button.html
+/// [`ZStack`]: struct.ZStack.html
 /// [`LifeCycle::HotChanged`]: ../enum.LifeCycle.html#variant.HotChanged
 pub struct Click<T> {
     /// A closure that will be invoked when the child widget is clicked.
@@ -51,11 +55,13 @@ impl<T: Data> Click<T> {
 
 impl<T: Data, W: Widget<T>> Controller<T, W> for Click<T> {
     fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let mut handled = false;
         match event {
             Event::MouseDown(mouse_event) => {
                 if mouse_event.button == MouseButton::Left {
                     ctx.set_active(true);
                     ctx.request_paint();
+                    handled = true;
                 }
             }
             Event::MouseUp(mouse_event) => {
@@ -65,12 +71,18 @@ impl<T: Data, W: Widget<T>> Controller<T, W> for Click<T> {
                         (self.action)(ctx, data, env);
                     }
                     ctx.request_paint();
+                    handled = true;
                 }
             }
             _ => {}
         }
 
         child.event(ctx, event, data, env);
+        // only after the child has seen it; otherwise its own `WidgetPod`s
+        // would skip the event.
+        if handled {
+            ctx.set_handled();
+        }
     }
 
     fn lifecycle(
@@ -12,9 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::kurbo::{Affine, Point, Rect, Size, Vec2};
+use crate::kurbo::{Affine, Point, Rect, Shape, Size, Vec2};
 use crate::widget::prelude::*;
-use crate::{Data, WidgetPod};
+use crate::{Data, KeyOrValue, WidgetPod};
 
 /// Represents the size and position of a rectangular "viewport" into a larger area.
 #[derive(Clone, Copy, Default, Debug, PartialEq)]
@@ -115,11 +115,22 @@ impl Viewport {
 
 /// A widget exposing a rectangular view into its child, which can be used as a building block for
 /// widgets that scroll their child.
+///
+/// The child's painting is clipped to the `ClipBox`'s bounds, optionally with
+/// [rounded corners], and mouse events outside of that shape don't reach the
+/// child unless it is hot or active. With both [`constrain_horizontal`] and
+/// [`constrain_vertical`] set, this can be used to hide the overflow of a
+/// child that doesn't scroll, like a round avatar image.
+///
+/// [rounded corners]: #method.rounded
+/// [`constrain_horizontal`]: #method.constrain_horizontal
+/// [`constrain_vertical`]: #method.constrain_vertical
 pub struct ClipBox<T, W> {
     child: WidgetPod<T, W>,
     port: Viewport,
     constrain_horizontal: bool,
     constrain_vertical: bool,
+    corner_radius: KeyOrValue<f64>,
 }
 
 impl<T, W: Widget<T>> ClipBox<T, W> {
@@ -130,6 +141,7 @@ impl<T, W: Widget<T>> ClipBox<T, W> {
             port: Default::default(),
             constrain_horizontal: false,
             constrain_vertical: false,
+            corner_radius: 0.0.into(),
         }
     }
 
@@ -204,6 +216,22 @@ impl<T, W: Widget<T>> ClipBox<T, W> {
         self.constrain_vertical = constrain;
     }
 
+    /// Builder-style method for rounding the corners of the clip shape.
+    ///
+    /// The argument can be either an `f64` or a [`Key<f64>`]. A radius of half
+    /// the size of a square `ClipBox` clips its child to a circle.
+    ///
+    /// [`Key<f64>`]: ../struct.Key.html
+    pub fn rounded(mut self, radius: impl Into<KeyOrValue<f64>>) -> Self {
+        self.set_rounded(radius);
+        self
+    }
+
+    /// Set the radius of the clip shape's corners.
+    pub fn set_rounded(&mut self, radius: impl Into<KeyOrValue<f64>>) {
+        self.corner_radius = radius.into();
+    }
+
     /// Changes the viewport offset by `delta`.
     ///
     /// Returns true if the offset actually changed. Even if `delta` is non-zero, the offset might
@@ -266,6 +294,22 @@ impl<T: Data, W: Widget<T>> Widget<T> for ClipBox<T, W> {
     fn event(&mut self, ctx: &mut EventCtx, ev: &Event, data: &mut T, env: &Env) {
         let viewport = ctx.size().to_rect();
         let force_event = self.child.is_hot() || self.child.has_active();
+        let corner_radius = self.corner_radius.resolve(env);
+        if corner_radius > 0.0 && !force_event {
+            // don't let the child see clicks in the clipped-off corners
+            let pos = match ev {
+                Event::MouseDown(mouse)
+                | Event::MouseUp(mouse)
+                | Event::MouseMove(mouse)
+                | Event::Wheel(mouse) => Some(mouse.pos),
+                _ => None,
+            };
+            if let Some(pos) = pos {
+                if !viewport.to_rounded_rect(corner_radius).contains(pos) {
+                    return;
+                }
+            }
+        }
         if let Some(child_event) =
             ev.transform_scroll(self.viewport_origin().to_vec2(), viewport, force_event)
         {
@@ -278,6 +322,9 @@ impl<T: Data, W: Widget<T>> Widget<T> for ClipBox<T, W> {
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        if ctx.env_key_changed(&self.corner_radius) {
+            ctx.request_paint();
+        }
         self.child.update(ctx, data, env);
     }
 
@@ -310,8 +357,13 @@ impl<T: Data, W: Widget<T>> Widget<T> for ClipBox<T, W> {
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
         let viewport = ctx.size().to_rect();
         let offset = self.viewport_origin().to_vec2();
+        let corner_radius = self.corner_radius.resolve(env);
         ctx.with_save(|ctx| {
-            ctx.clip(viewport);
+            if corner_radius > 0.0 {
+                ctx.clip(viewport.to_rounded_rect(corner_radius));
+            } else {
+                ctx.clip(viewport);
+            }
             ctx.transform(Affine::translate(-offset));
 
             let mut visible = ctx.region().clone();
@@ -0,0 +1,126 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that adds extra constraints to its child.
+
+use crate::widget::prelude::*;
+use crate::Data;
+
+/// A widget that imposes additional [`BoxConstraints`] on its child.
+///
+/// The extra constraints are intersected with the ones from the parent, so
+/// the child gets a range that satisfies both; where they disagree, the
+/// parent wins. This is unlike [`fix_width`] and friends, which
+/// ask for a specific size: a `ConstrainedBox` with a minimum width of 300
+/// still fits in a window that is only 200 wide.
+///
+/// ```
+/// use druid::widget::{ConstrainedBox, TextBox};
+/// use druid::{BoxConstraints, Size};
+///
+/// // at least 100 wide, but no wider than 400
+/// let bc = BoxConstraints::new(Size::new(100., 0.), Size::new(400., f64::INFINITY));
+/// let input = ConstrainedBox::new(TextBox::<String>::new(), bc);
+/// ```
+///
+/// [`BoxConstraints`]: ../struct.BoxConstraints.html
+/// [`fix_width`]: ../trait.WidgetExt.html#method.fix_width
+pub struct ConstrainedBox<T> {
+    inner: Box<dyn Widget<T>>,
+    constraints: BoxConstraints,
+}
+
+impl<T> ConstrainedBox<T> {
+    /// Create a new `ConstrainedBox` that applies `constraints` to `inner`.
+    pub fn new(inner: impl Widget<T> + 'static, constraints: BoxConstraints) -> Self {
+        ConstrainedBox {
+            inner: Box::new(inner),
+            constraints,
+        }
+    }
+
+    /// The constraints this widget adds.
+    pub fn constraints(&self) -> BoxConstraints {
+        self.constraints
+    }
+
+    /// Set the constraints this widget adds.
+    ///
+    /// If you change this property, you are responsible for calling
+    /// [`request_layout`] to ensure the widget is updated.
+    ///
+    /// [`request_layout`]: ../struct.EventCtx.html#method.request_layout
+    pub fn set_constraints(&mut self, constraints: BoxConstraints) {
+        self.constraints = constraints;
+    }
+
+    /// Our constraints, clamped to fit inside the parent's.
+    fn child_constraints(&self, bc: &BoxConstraints) -> BoxConstraints {
+        let min = self.constraints.min().clamp(bc.min(), bc.max());
+        let max = self.constraints.max().clamp(min, bc.max());
+        BoxConstraints::new(min, max)
+    }
+}
+
+impl<T: Data> Widget<T> for ConstrainedBox<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.inner.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.inner.lifecycle(ctx, event, data, env)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        self.inner.update(ctx, old_data, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("ConstrainedBox");
+
+        let child_bc = self.child_constraints(bc);
+        let size = self.inner.layout(ctx, &child_bc, data, env);
+        child_bc.constrain(size)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.inner.paint(ctx, data, env);
+    }
+
+    fn id(&self) -> Option<WidgetId> {
+        self.inner.id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::SizedBox;
+
+    #[test]
+    fn intersects_with_parent() {
+        let bc = BoxConstraints::new(Size::new(100., 20.), Size::new(300., 50.));
+        let constrained = ConstrainedBox::<()>::new(SizedBox::empty(), bc);
+
+        let loose = BoxConstraints::tight(Size::new(400., 400.)).loosen();
+        let child_bc = constrained.child_constraints(&loose);
+        assert_eq!(child_bc, bc);
+
+        // the parent doesn't allow our minimum, so it wins
+        let narrow = BoxConstraints::new(Size::new(0., 30.), Size::new(80., 400.));
+        let child_bc = constrained.child_constraints(&narrow);
+        assert_eq!(child_bc.min(), Size::new(80., 30.));
+        assert_eq!(child_bc.max(), Size::new(80., 50.));
+    }
+}
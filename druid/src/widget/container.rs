@@ -14,20 +14,38 @@
 
 //! A widget that provides simple visual styling options to a child.
 
-use super::BackgroundBrush;
+use super::{BackgroundBrush, ThemedGradient};
+use crate::kurbo::{Insets, Rect, RoundedRect};
 use crate::widget::prelude::*;
 use crate::{Color, Data, KeyOrValue, Point, WidgetPod};
 
 struct BorderStyle {
-    width: KeyOrValue<f64>,
+    width: BorderWidth,
     color: KeyOrValue<Color>,
 }
 
+enum BorderWidth {
+    /// The same width on every side, stroked along the (rounded) outline.
+    Uniform(KeyOrValue<f64>),
+    /// A separate width for each side, each filled as a rectangle.
+    Sides(Insets),
+}
+
+impl BorderWidth {
+    fn resolve(&self, env: &Env) -> Insets {
+        match self {
+            BorderWidth::Uniform(width) => Insets::uniform(width.resolve(env)),
+            BorderWidth::Sides(insets) => *insets,
+        }
+    }
+}
+
 /// A widget that provides simple visual styling options to a child.
 pub struct Container<T> {
     background: Option<BackgroundBrush<T>>,
     border: Option<BorderStyle>,
     corner_radius: KeyOrValue<f64>,
+    padding: Insets,
 
     inner: WidgetPod<T, Box<dyn Widget<T>>>,
 }
@@ -39,6 +57,7 @@ impl<T: Data> Container<T> {
             background: None,
             border: None,
             corner_radius: 0.0.into(),
+            padding: Insets::ZERO,
             inner: WidgetPod::new(inner).boxed(),
         }
     }
@@ -47,11 +66,12 @@ impl<T: Data> Container<T> {
     ///
     /// This can be passed anything which can be represented by a [`BackgroundBrush`];
     /// noteably, it can be any [`Color`], a [`Key<Color>`] resolvable in the [`Env`],
-    /// any gradient, or a fully custom [`Painter`] widget.
+    /// a [`KeyOrValue<Color>`], any gradient, or a fully custom [`Painter`] widget.
     ///
     /// [`BackgroundBrush`]: ../enum.BackgroundBrush.html
     /// [`Color`]: ../enum.Color.html
     /// [`Key<Color>`]: ../struct.Key.html
+    /// [`KeyOrValue<Color>`]: ../enum.KeyOrValue.html
     /// [`Env`]: ../struct.Env.html
     /// [`Painter`]: struct.Painter.html
     pub fn background(mut self, brush: impl Into<BackgroundBrush<T>>) -> Self {
@@ -63,17 +83,50 @@ impl<T: Data> Container<T> {
     ///
     /// This can be passed anything which can be represented by a [`BackgroundBrush`];
     /// noteably, it can be any [`Color`], a [`Key<Color>`] resolvable in the [`Env`],
-    /// any gradient, or a fully custom [`Painter`] widget.
+    /// a [`KeyOrValue<Color>`], any gradient, or a fully custom [`Painter`] widget.
     ///
     /// [`BackgroundBrush`]: ../enum.BackgroundBrush.html
     /// [`Color`]: ../enum.Color.html
     /// [`Key<Color>`]: ../struct.Key.html
+    /// [`KeyOrValue<Color>`]: ../enum.KeyOrValue.html
     /// [`Env`]: ../struct.Env.html
     /// [`Painter`]: struct.Painter.html
     pub fn set_background(&mut self, brush: impl Into<BackgroundBrush<T>>) {
         self.background = Some(brush.into());
     }
 
+    /// Builder-style method for setting a linear gradient as the background.
+    ///
+    /// The gradient runs across the center of the widget at `angle`, in
+    /// radians clockwise from the positive x axis, through the evenly spaced
+    /// `stops`. The stops can be [`Color`]s or [`Key<Color>`]s, which are
+    /// resolved in the [`Env`] every time the background is painted, so the
+    /// gradient follows the theme.
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use druid::widget::{Container, Label};
+    /// use druid::{theme, Color, KeyOrValue};
+    ///
+    /// let stops: Vec<KeyOrValue<Color>> = vec![theme::BUTTON_LIGHT.into(), Color::BLACK.into()];
+    /// let header = Container::new(Label::<()>::new("Header")).linear_gradient(FRAC_PI_2, stops);
+    /// ```
+    ///
+    /// This is a shorthand for [`background`] with a [`ThemedGradient`].
+    ///
+    /// [`Color`]: ../enum.Color.html
+    /// [`Key<Color>`]: ../struct.Key.html
+    /// [`Env`]: ../struct.Env.html
+    /// [`background`]: #method.background
+    /// [`ThemedGradient`]: struct.ThemedGradient.html
+    pub fn linear_gradient(
+        self,
+        angle: f64,
+        stops: impl IntoIterator<Item = impl Into<KeyOrValue<Color>>>,
+    ) -> Self {
+        self.background(ThemedGradient::with_angle(angle, stops))
+    }
+
     /// Builder-style method for painting a border around the widget with a color and width.
     ///
     /// Arguments can be either concrete values, or a [`Key`] of the respective
@@ -102,21 +155,100 @@ impl<T: Data> Container<T> {
     ) {
         self.border = Some(BorderStyle {
             color: color.into(),
-            width: width.into(),
+            width: BorderWidth::Uniform(width.into()),
+        });
+    }
+
+    /// Builder-style method for painting a border with a different width on
+    /// each side, such as only a bottom border for an underlined input.
+    ///
+    /// Each side is painted as a plain rectangle, so this kind of border
+    /// doesn't follow [rounded] corners. The color can be either a concrete
+    /// value, or a [`Key`] resolvable in the [`Env`].
+    ///
+    /// [rounded]: #method.rounded
+    /// [`Key`]: ../struct.Key.html
+    /// [`Env`]: ../struct.Env.html
+    pub fn border_sides(
+        mut self,
+        widths: impl Into<Insets>,
+        color: impl Into<KeyOrValue<Color>>,
+    ) -> Self {
+        self.set_border_sides(widths, color);
+        self
+    }
+
+    /// Paint a border with a different width on each side.
+    ///
+    /// See [`border_sides`] for more details.
+    ///
+    /// [`border_sides`]: #method.border_sides
+    pub fn set_border_sides(
+        &mut self,
+        widths: impl Into<Insets>,
+        color: impl Into<KeyOrValue<Color>>,
+    ) {
+        self.border = Some(BorderStyle {
+            color: color.into(),
+            width: BorderWidth::Sides(widths.into().nonnegative()),
         });
     }
 
-    /// Builder style method for rounding off corners of this container by setting a corner radius
+    /// Builder style method for rounding off corners of this container by setting a corner radius.
+    ///
+    /// Both the background and the border follow the rounded shape. The argument
+    /// can be either a concrete value, or a [`Key`] resolvable in the [`Env`];
+    /// a radius of `0.0` (the default) leaves the corners square.
+    ///
+    /// [`Key`]: ../struct.Key.html
+    /// [`Env`]: ../struct.Env.html
     pub fn rounded(mut self, radius: impl Into<KeyOrValue<f64>>) -> Self {
         self.set_rounded(radius);
         self
     }
 
-    /// Round off corners of this container by setting a corner radius
+    /// Round off corners of this container by setting a corner radius.
     pub fn set_rounded(&mut self, radius: impl Into<KeyOrValue<f64>>) {
         self.corner_radius = radius.into();
     }
 
+    /// Builder-style method for adding padding between the border and the
+    /// child.
+    ///
+    /// Unlike [`WidgetExt::padding`], which wraps the container in a
+    /// [`Padding`], this space is inside the container: the background and
+    /// border surround it. This takes the same arguments as [`Padding::new`].
+    ///
+    /// ```
+    /// use druid::widget::{Container, Label};
+    /// use druid::{Color, Widget, WidgetExt};
+    ///
+    /// // 10 between the text and the border, and 5 outside the border.
+    /// let card: Box<dyn Widget<()>> = Container::new(Label::new("inside"))
+    ///     .border(Color::WHITE, 1.0)
+    ///     .inner_padding(10.0)
+    ///     .padding(5.0)
+    ///     .boxed();
+    /// ```
+    ///
+    /// [`Padding`]: struct.Padding.html
+    /// [`Padding::new`]: struct.Padding.html#method.new
+    /// [`WidgetExt::padding`]: trait.WidgetExt.html#method.padding
+    pub fn inner_padding(mut self, insets: impl Into<Insets>) -> Self {
+        self.set_inner_padding(insets);
+        self
+    }
+
+    /// Set the padding between the border and the child.
+    ///
+    /// If you change this property, you are responsible for calling
+    /// [`request_layout`] to ensure the widget is updated.
+    ///
+    /// [`request_layout`]: ../struct.EventCtx.html#method.request_layout
+    pub fn set_inner_padding(&mut self, insets: impl Into<Insets>) {
+        self.padding = insets.into();
+    }
+
     #[cfg(test)]
     pub(crate) fn background_is_some(&self) -> bool {
         self.background.is_some()
@@ -141,25 +273,50 @@ impl<T: Data> Widget<T> for Container<T> {
         if let Some(BackgroundBrush::Painter(p)) = self.background.as_mut() {
             p.update(ctx, old_data, data, env);
         }
+        if let Some(background) = &self.background {
+            if background.env_key_changed(ctx) {
+                ctx.request_paint();
+            }
+        }
+        if let Some(border) = &self.border {
+            if let BorderWidth::Uniform(width) = &border.width {
+                if ctx.env_key_changed(width) {
+                    ctx.request_layout();
+                }
+            }
+            if ctx.env_key_changed(&border.color) {
+                ctx.request_paint();
+            }
+        }
+        if ctx.env_key_changed(&self.corner_radius) {
+            ctx.request_paint();
+        }
         self.inner.update(ctx, data, env);
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
         bc.debug_check("Container");
 
-        // Shrink constraints by border offset
-        let border_width = match &self.border {
+        // Shrink constraints by border and padding offset
+        let border = match &self.border {
             Some(border) => border.width.resolve(env),
-            None => 0.0,
+            None => Insets::ZERO,
         };
-        let child_bc = bc.shrink((2.0 * border_width, 2.0 * border_width));
+        let padding = self.padding;
+        let insets = Insets::new(
+            border.x0 + padding.x0,
+            border.y0 + padding.y0,
+            border.x1 + padding.x1,
+            border.y1 + padding.y1,
+        );
+        let child_bc = bc.shrink(insets.size());
         let size = self.inner.layout(ctx, &child_bc, data, env);
-        let origin = Point::new(border_width, border_width);
+        let origin = Point::new(insets.x0, insets.y0);
         self.inner.set_origin(ctx, data, env, origin);
 
         let my_size = Size::new(
-            size.width + 2.0 * border_width,
-            size.height + 2.0 * border_width,
+            size.width + insets.x_value(),
+            size.height + insets.y_value(),
         );
 
         let my_insets = self.inner.compute_parent_paint_insets(my_size);
@@ -180,15 +337,76 @@ impl<T: Data> Widget<T> for Container<T> {
         }
 
         if let Some(border) = &self.border {
-            let border_width = border.width.resolve(env);
-            let border_rect = ctx
-                .size()
-                .to_rect()
-                .inset(border_width / -2.0)
-                .to_rounded_rect(corner_radius);
-            ctx.stroke(border_rect, &border.color.resolve(env), border_width);
+            let color = border.color.resolve(env);
+            match &border.width {
+                BorderWidth::Uniform(width) => {
+                    let border_width = width.resolve(env);
+                    let border_rect = border_rect(ctx.size(), border_width, corner_radius);
+                    ctx.stroke(border_rect, &color, border_width);
+                }
+                BorderWidth::Sides(widths) => {
+                    for side in &border_sides(ctx.size(), *widths) {
+                        if side.area() > 0.0 {
+                            ctx.fill(*side, &color);
+                        }
+                    }
+                }
+            }
         };
 
         self.inner.paint(ctx, data, env);
     }
 }
+
+/// The path to stroke for a border of the given width, so that the stroke
+/// stays inside `size`, and its outer edge follows the background's corners.
+fn border_rect(size: Size, border_width: f64, corner_radius: f64) -> RoundedRect {
+    size.to_rect()
+        .inset(border_width / -2.0)
+        .to_rounded_rect((corner_radius - border_width / 2.0).max(0.0))
+}
+
+/// The rectangles covered by the top, bottom, left and right sides of a
+/// border with the given widths. The top and bottom sides cover the corners.
+fn border_sides(size: Size, widths: Insets) -> [Rect; 4] {
+    let (w, h) = (size.width, size.height);
+    let inner_y0 = widths.y0.min(h);
+    let inner_y1 = (h - widths.y1).max(inner_y0);
+    [
+        Rect::new(0.0, 0.0, w, inner_y0),
+        Rect::new(0.0, inner_y1, w, h),
+        Rect::new(0.0, inner_y0, widths.x0.min(w), inner_y1),
+        Rect::new((w - widths.x1).max(0.0), inner_y0, w, inner_y1),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn border_stays_inside() {
+        let size = Size::new(100.0, 50.0);
+        for &(width, radius) in &[(1.0, 0.0), (4.0, 0.0), (4.0, 10.0), (10.0, 2.0)] {
+            let path = border_rect(size, width, radius);
+            // the outer edge of the stroke is half the width outside the path
+            assert_eq!(path.rect().inset(width / 2.0), size.to_rect());
+            assert_eq!(path.radius() + width / 2.0, radius.max(width / 2.0));
+        }
+    }
+
+    #[test]
+    fn border_sides_are_inside() {
+        let size = Size::new(100.0, 50.0);
+        let [top, bottom, left, right] = border_sides(size, Insets::new(1.0, 0.0, 3.0, 2.0));
+        assert_eq!(top, Rect::new(0.0, 0.0, 100.0, 0.0));
+        assert_eq!(bottom, Rect::new(0.0, 48.0, 100.0, 50.0));
+        assert_eq!(left, Rect::new(0.0, 0.0, 1.0, 48.0));
+        assert_eq!(right, Rect::new(97.0, 0.0, 100.0, 48.0));
+
+        // sides wider than the container don't spill out of it
+        for side in &border_sides(size, Insets::uniform(80.0)) {
+            assert_eq!(side.intersect(size.to_rect()), *side);
+        }
+    }
+}
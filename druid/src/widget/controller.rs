@@ -58,7 +58,7 @@ use crate::widget::prelude::*;
 /// [`Widget`]: ../trait.Widget.html
 /// [`TextBox`]: struct.TextBox.html
 /// [`ControllerHost`]: struct.ControllerHost.html
-/// [`WidgetExt::controller`]: ../trait.WidgetExt.html#tymethod.controller
+/// [`WidgetExt::controller`]: ../trait.WidgetExt.html#method.controller
 pub trait Controller<T, W: Widget<T>> {
     /// Analogous to [`Widget::event`].
     ///
@@ -103,6 +103,26 @@ impl<W, C> ControllerHost<W, C> {
     pub fn new(widget: W, controller: C) -> ControllerHost<W, C> {
         ControllerHost { widget, controller }
     }
+
+    /// Returns a reference to the child widget.
+    pub fn child(&self) -> &W {
+        &self.widget
+    }
+
+    /// Returns a mutable reference to the child widget.
+    pub fn child_mut(&mut self) -> &mut W {
+        &mut self.widget
+    }
+
+    /// Returns a reference to the controller.
+    pub fn controller_ref(&self) -> &C {
+        &self.controller
+    }
+
+    /// Returns a mutable reference to the controller.
+    pub fn controller_mut(&mut self) -> &mut C {
+        &mut self.controller
+    }
 }
 
 impl<T, W: Widget<T>, C: Controller<T, W>> Widget<T> for ControllerHost<W, C> {
@@ -0,0 +1,83 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [`Controller`] that reacts to data changes once they settle down.
+//!
+//! [`Controller`]: struct.Controller.html
+
+use std::time::Duration;
+
+use crate::widget::Controller;
+use crate::{Data, Env, Event, EventCtx, TimerToken, UpdateCtx, Widget};
+
+/// A [`Controller`] that calls a closure once its data has stopped changing
+/// for a while. It is available as a `debounce` method via [`WidgetExt`].
+///
+/// Every change to the data restarts the countdown, so a burst of changes,
+/// like typing into a [`TextBox`], results in a single call with the final
+/// value. This is useful for expensive reactions, like running a search.
+///
+/// If the widget is removed before the countdown ends, the closure is not
+/// called.
+///
+/// ```
+/// use std::time::Duration;
+/// use druid::widget::TextBox;
+/// use druid::WidgetExt;
+///
+/// let search = TextBox::new().debounce(Duration::from_millis(300), |query: &String, _env| {
+///     println!("searching for {}", query);
+/// });
+/// ```
+///
+/// [`Controller`]: trait.Controller.html
+/// [`WidgetExt`]: ../trait.WidgetExt.html
+/// [`TextBox`]: struct.TextBox.html
+pub struct Debounce<T> {
+    delay: Duration,
+    action: Box<dyn Fn(&T, &Env)>,
+    timer: TimerToken,
+}
+
+impl<T: Data> Debounce<T> {
+    /// Create a new `Debounce` controller, calling `action` after the data
+    /// has been unchanged for `delay`.
+    pub fn new(delay: Duration, action: impl Fn(&T, &Env) + 'static) -> Self {
+        Debounce {
+            delay,
+            action: Box::new(action),
+            timer: TimerToken::INVALID,
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> Controller<T, W> for Debounce<T> {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::Timer(token) if *token == self.timer => {
+                self.timer = TimerToken::INVALID;
+                (self.action)(data, env);
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+
+    fn update(&mut self, child: &mut W, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if !old_data.same(data) {
+            // this forgets the previous timer, if there was one
+            self.timer = ctx.request_timer(self.delay);
+        }
+        child.update(ctx, old_data, data, env)
+    }
+}
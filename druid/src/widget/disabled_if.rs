@@ -0,0 +1,119 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that can disable its child based on the data.
+
+use super::fade_to_background::cover_with_background;
+use crate::widget::prelude::*;
+use crate::{theme, Data, Point, WidgetPod};
+
+/// A widget that disables its child when a predicate on the data is `true`.
+///
+/// While disabled, the child does not receive clicks, scrolling or keyboard
+/// input. Mouse moves are still passed through, so that the child's hot
+/// state is up to date when it is enabled again. Other events, as well as
+/// [`update`] and [`layout`], are passed through too, so that the child
+/// keeps its state and size.
+///
+/// A disabled child is dimmed the same way as by [`FadeToBackground`]: it is
+/// covered with the window background color, at an alpha of
+/// [`theme::DISABLED_DIM_FACTOR`]. This looks right as long as the child
+/// sits directly on the window background.
+///
+/// This is available as [`WidgetExt::disabled_if`] for convenience.
+///
+/// [`FadeToBackground`]: struct.FadeToBackground.html
+/// [`theme::DISABLED_DIM_FACTOR`]: ../theme/constant.DISABLED_DIM_FACTOR.html
+/// [`update`]: ../trait.Widget.html#tymethod.update
+/// [`layout`]: ../trait.Widget.html#tymethod.layout
+/// [`WidgetExt::disabled_if`]: ../trait.WidgetExt.html#method.disabled_if
+pub struct DisabledIf<T, W> {
+    child: WidgetPod<T, W>,
+    disabled_if: Box<dyn Fn(&T, &Env) -> bool>,
+    is_disabled: bool,
+}
+
+impl<T: Data, W: Widget<T>> DisabledIf<T, W> {
+    /// Create a new widget that disables `child` whenever `disabled_if`
+    /// returns `true`.
+    pub fn new(child: W, disabled_if: impl Fn(&T, &Env) -> bool + 'static) -> Self {
+        DisabledIf {
+            child: WidgetPod::new(child),
+            disabled_if: Box::new(disabled_if),
+            is_disabled: false,
+        }
+    }
+
+    /// Returns `true` if the child is currently disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for DisabledIf<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let is_input = matches!(
+            event,
+            Event::MouseDown(_)
+                | Event::MouseUp(_)
+                | Event::Wheel(_)
+                | Event::KeyDown(_)
+                | Event::KeyUp(_)
+                | Event::Paste(_)
+                | Event::Zoom(_)
+        );
+        // an interaction that was in progress when we became disabled is
+        // allowed to finish, so that the child doesn't get stuck as active.
+        if self.is_disabled && is_input && !self.child.has_active() {
+            return;
+        }
+        self.child.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let LifeCycle::WidgetAdded = event {
+            self.is_disabled = (self.disabled_if)(data, env);
+        }
+        self.child.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        let is_disabled = (self.disabled_if)(data, env);
+        if is_disabled != self.is_disabled {
+            self.is_disabled = is_disabled;
+            ctx.request_paint();
+        }
+        if ctx.env_key_changed(&theme::DISABLED_DIM_FACTOR) {
+            ctx.request_paint();
+        }
+        self.child.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("DisabledIf");
+        let size = self.child.layout(ctx, bc, data, env);
+        self.child.set_origin(ctx, data, env, Point::ORIGIN);
+        ctx.set_paint_insets(self.child.paint_insets());
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.child.paint(ctx, data, env);
+
+        if self.is_disabled {
+            let dim = env.get(theme::DISABLED_DIM_FACTOR).max(0.0).min(1.0);
+            cover_with_background(ctx, self.child.paint_rect(), dim, env);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Drag and drop between widgets of the same window.
+
+use std::any::Any;
+
+use crate::widget::prelude::*;
+use crate::widget::Controller;
+use crate::{theme, MouseButton, Point, WidgetPod};
+
+/// How far the mouse has to move while pressed before a drag starts, so
+/// that an ordinary click doesn't become a drag.
+const DRAG_THRESHOLD: f64 = 4.0;
+
+/// The width of the outline painted around a [`DropTarget`] that would
+/// accept the current drag.
+const HIGHLIGHT_WIDTH: f64 = 2.0;
+
+/// A [`Controller`] that lets the user drag a payload out of its child.
+///
+/// A drag starts when the left mouse button is pressed on the child and the
+/// mouse then moves a few pixels. The payload is computed from the data at
+/// that moment, and can be dropped onto a [`DropTarget`]. This is available
+/// as [`WidgetExt::draggable`] for convenience.
+///
+/// The drag is tracked by the window, through [`EventCtx::start_drag`], so
+/// it only works within a single window.
+///
+/// [`Controller`]: trait.Controller.html
+/// [`DropTarget`]: struct.DropTarget.html
+/// [`WidgetExt::draggable`]: ../trait.WidgetExt.html#method.draggable
+/// [`EventCtx::start_drag`]: ../struct.EventCtx.html#method.start_drag
+pub struct DragSource<T, P> {
+    payload: Box<dyn Fn(&T, &Env) -> P>,
+    /// Where the mouse was pressed, until the drag starts or the mouse is released.
+    press: Option<Point>,
+}
+
+impl<T, P: Any> DragSource<T, P> {
+    /// Create a new `DragSource`, computing the payload with `payload`.
+    pub fn new(payload: impl Fn(&T, &Env) -> P + 'static) -> Self {
+        DragSource {
+            payload: Box::new(payload),
+            press: None,
+        }
+    }
+}
+
+impl<T: Data, P: Any, W: Widget<T>> Controller<T, W> for DragSource<T, P> {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseDown(mouse) if mouse.button == MouseButton::Left => {
+                self.press = Some(mouse.window_pos);
+                ctx.set_active(true);
+            }
+            Event::MouseMove(mouse) => {
+                if let Some(press) = self.press {
+                    if press.distance(mouse.window_pos) >= DRAG_THRESHOLD {
+                        self.press = None;
+                        ctx.start_drag((self.payload)(data, env));
+                    }
+                }
+            }
+            Event::MouseUp(mouse) if mouse.button == MouseButton::Left => {
+                self.press = None;
+                ctx.set_active(false);
+            }
+            _ => {}
+        }
+        child.event(ctx, event, data, env);
+    }
+}
+
+/// A widget that accepts payloads dropped onto it from a [`DragSource`].
+///
+/// While a payload of type `P` that `accept` agrees to is dragged over the
+/// child, it is outlined in [`theme::PRIMARY_LIGHT`]; releasing the mouse
+/// there calls `on_drop`. This is available as [`WidgetExt::drop_target`]
+/// for convenience.
+///
+/// [`DragSource`]: struct.DragSource.html
+/// [`theme::PRIMARY_LIGHT`]: ../theme/constant.PRIMARY_LIGHT.html
+/// [`WidgetExt::drop_target`]: ../trait.WidgetExt.html#method.drop_target
+pub struct DropTarget<T, P, W> {
+    child: WidgetPod<T, W>,
+    accept: Box<dyn Fn(&P, &T) -> bool>,
+    on_drop: Box<dyn Fn(&mut EventCtx, &P, &mut T, &Env)>,
+    /// `true` while an acceptable payload is dragged over us.
+    is_highlighted: bool,
+}
+
+impl<T: Data, P: Any + Clone, W: Widget<T>> DropTarget<T, P, W> {
+    /// Create a new `DropTarget` around `child`.
+    ///
+    /// `accept` decides whether a payload can be dropped here, and `on_drop`
+    /// is called when it is.
+    pub fn new(
+        child: W,
+        accept: impl Fn(&P, &T) -> bool + 'static,
+        on_drop: impl Fn(&mut EventCtx, &P, &mut T, &Env) + 'static,
+    ) -> Self {
+        DropTarget {
+            child: WidgetPod::new(child),
+            accept: Box::new(accept),
+            on_drop: Box::new(on_drop),
+            is_highlighted: false,
+        }
+    }
+
+    /// The payload of the current drag, if we are hovered and would accept it.
+    fn acceptable_payload(&self, ctx: &EventCtx, data: &T) -> Option<P> {
+        ctx.drag_payload::<P>()
+            .filter(|payload| ctx.is_hot() && (self.accept)(payload, data))
+            .cloned()
+    }
+
+    fn set_highlighted(&mut self, highlighted: bool) -> bool {
+        let changed = self.is_highlighted != highlighted;
+        self.is_highlighted = highlighted;
+        changed
+    }
+}
+
+impl<T: Data, P: Any + Clone, W: Widget<T>> Widget<T> for DropTarget<T, P, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseMove(_) => {
+                let highlighted = self.acceptable_payload(ctx, data).is_some();
+                if self.set_highlighted(highlighted) {
+                    ctx.request_paint();
+                }
+            }
+            Event::MouseUp(mouse) if mouse.button == MouseButton::Left => {
+                if let Some(payload) = self.acceptable_payload(ctx, data) {
+                    (self.on_drop)(ctx, &payload, data, env);
+                }
+                if self.set_highlighted(false) {
+                    ctx.request_paint();
+                }
+            }
+            _ => {}
+        }
+        self.child.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        if let LifeCycle::HotChanged(false) = event {
+            if self.set_highlighted(false) {
+                ctx.request_paint();
+            }
+        }
+        self.child.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.child.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("DropTarget");
+        let size = self.child.layout(ctx, bc, data, env);
+        self.child.set_origin(ctx, data, env, Point::ORIGIN);
+        ctx.set_paint_insets(self.child.paint_insets());
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.child.paint(ctx, data, env);
+
+        if self.is_highlighted {
+            let outline = ctx.size().to_rect().inset(-HIGHLIGHT_WIDTH / 2.0);
+            ctx.stroke(outline, &env.get(theme::PRIMARY_LIGHT), HIGHLIGHT_WIDTH);
+        }
+    }
+}
@@ -30,6 +30,11 @@ impl<T> Either<T> {
     ///
     /// The given closure is evaluated on data change. If its value is `true`, then
     /// the `true_branch` widget is shown, otherwise `false_branch`.
+    ///
+    /// Both branches receive [`update`] calls, regardless of which one is
+    /// currently shown.
+    ///
+    /// [`update`]: trait.Widget.html#tymethod.update
     pub fn new(
         closure: impl Fn(&T, &Env) -> bool + 'static,
         true_branch: impl Widget<T> + 'static,
@@ -73,7 +78,10 @@ impl<T: Data> Widget<T> for Either<T> {
             self.current = current;
             ctx.request_layout();
         }
-        self.current_widget().update(ctx, data, env)
+        // The hidden branch is updated as well, so that its state is current
+        // when it is next shown.
+        self.true_branch.update(ctx, data, env);
+        self.false_branch.update(ctx, data, env);
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
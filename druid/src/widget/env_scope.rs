@@ -28,6 +28,11 @@ impl<T, W: Widget<T>> EnvScope<T, W> {
     ///
     /// Accepts a closure that sets Env values.
     ///
+    /// The closure is given a copy of the [`Env`] that this widget received
+    /// from its parent, so reading a key returns the inherited value. This
+    /// makes it possible to derive new values from existing ones. Changes
+    /// only affect the descendants of this widget.
+    ///
     /// This is available as [`WidgetExt::env_scope`] for convenience.
     ///
     /// # Examples
@@ -46,6 +51,23 @@ impl<T, W: Widget<T>> EnvScope<T, W> {
     /// # }
     /// ```
     ///
+    /// Doubling the inherited padding for a subtree:
+    ///
+    /// ```
+    /// # use druid::{theme, Widget};
+    /// # use druid::widget::{Label, EnvScope};
+    /// # fn build_widget() -> impl Widget<String> {
+    /// EnvScope::new(
+    ///     |env, _data| {
+    ///         let padding = env.get(theme::WIDGET_PADDING_HORIZONTAL);
+    ///         env.set(theme::WIDGET_PADDING_HORIZONTAL, padding * 2.0);
+    ///     },
+    ///     Label::new("Spacious!")
+    /// )
+    /// # }
+    /// ```
+    ///
+    /// [`Env`]: ../struct.Env.html
     /// [`WidgetExt::env_scope`]: ../trait.WidgetExt.html#method.env_scope
     pub fn new(f: impl Fn(&mut Env, &T) + 'static, child: W) -> EnvScope<T, W> {
         EnvScope {
@@ -69,10 +91,21 @@ impl<T: Data, W: Widget<T>> Widget<T> for EnvScope<T, W> {
         self.child.lifecycle(ctx, event, data, &new_env)
     }
 
-    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
         let mut new_env = env.clone();
         (self.f)(&mut new_env, &data);
 
+        // Widgets are not required to repaint when the env changes, so if our
+        // closure produces a different env for the new data we repaint the
+        // whole subtree ourselves.
+        if !old_data.same(data) {
+            let mut old_env = env.clone();
+            (self.f)(&mut old_env, &old_data);
+            if !old_env.same(&new_env) {
+                ctx.request_paint();
+            }
+        }
+
         self.child.update(ctx, data, &new_env);
     }
 
@@ -83,7 +116,8 @@ impl<T: Data, W: Widget<T>> Widget<T> for EnvScope<T, W> {
         (self.f)(&mut new_env, &data);
 
         let size = self.child.layout(ctx, &bc, data, &new_env);
-        self.child.set_origin(ctx, data, env, Point::ORIGIN);
+        self.child.set_origin(ctx, data, &new_env, Point::ORIGIN);
+        ctx.set_paint_insets(self.child.paint_insets());
         size
     }
 
@@ -0,0 +1,150 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that fades its child towards the window background.
+
+use crate::widget::prelude::*;
+use crate::{theme, Data, Point, Rect, WidgetPod};
+
+/// A widget that fades its child towards the window background color.
+///
+/// This is not real transparency: the render context has no way to paint a
+/// child into a layer with an alpha. Instead the child is painted as usual,
+/// and then covered with [`theme::WINDOW_BACKGROUND_COLOR`] at an alpha of
+/// `1.0 - opacity`. That only looks like transparency if the child sits
+/// directly on the window background; over anything else, like the
+/// background of a [`Container`], a gradient or an image, the child fades
+/// to the window background color instead of to what is behind it.
+///
+/// An opacity of `1.0` paints the child as normal, and `0.0` doesn't paint
+/// it at all, so that whatever is behind it shows.
+///
+/// Only painting is affected: the child still gets events and takes up space
+/// at any opacity. To fade a widget in or out, call [`set_opacity`] from an
+/// [`AnimFrame`] handler, and request paint.
+///
+/// This is available as [`WidgetExt::fade_to_background`] for convenience.
+///
+/// [`theme::WINDOW_BACKGROUND_COLOR`]: ../theme/constant.WINDOW_BACKGROUND_COLOR.html
+/// [`Container`]: struct.Container.html
+/// [`set_opacity`]: #method.set_opacity
+/// [`AnimFrame`]: ../enum.Event.html#variant.AnimFrame
+/// [`WidgetExt::fade_to_background`]: ../trait.WidgetExt.html#method.fade_to_background
+pub struct FadeToBackground<T, W> {
+    child: WidgetPod<T, W>,
+    opacity: f64,
+}
+
+impl<T: Data, W: Widget<T>> FadeToBackground<T, W> {
+    /// Create a new widget that fades `child` to the given opacity.
+    ///
+    /// The opacity is clamped to the range `0.0..=1.0`.
+    pub fn new(child: W, opacity: f64) -> Self {
+        FadeToBackground {
+            child: WidgetPod::new(child),
+            opacity: clamp_opacity(opacity),
+        }
+    }
+
+    /// Returns the current opacity.
+    pub fn opacity(&self) -> f64 {
+        self.opacity
+    }
+
+    /// Set the opacity, clamped to the range `0.0..=1.0`.
+    ///
+    /// The caller is responsible for requesting paint.
+    pub fn set_opacity(&mut self, opacity: f64) {
+        self.opacity = clamp_opacity(opacity);
+    }
+
+    /// Returns a reference to the child widget.
+    pub fn child(&self) -> &W {
+        self.child.widget()
+    }
+
+    /// Returns a mutable reference to the child widget.
+    pub fn child_mut(&mut self) -> &mut W {
+        self.child.widget_mut()
+    }
+}
+
+fn clamp_opacity(opacity: f64) -> f64 {
+    if opacity.is_nan() {
+        1.0
+    } else {
+        opacity.max(0.0).min(1.0)
+    }
+}
+
+/// Cover `rect` with the window background color, at an alpha of `amount`.
+pub(crate) fn cover_with_background(ctx: &mut PaintCtx, rect: Rect, amount: f64, env: &Env) {
+    let color = env.get(theme::WINDOW_BACKGROUND_COLOR).with_alpha(amount);
+    ctx.fill(rect, &color);
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for FadeToBackground<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        self.child.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.child.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        if self.opacity < 1.0 && ctx.env_key_changed(&theme::WINDOW_BACKGROUND_COLOR) {
+            ctx.request_paint();
+        }
+        self.child.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("FadeToBackground");
+        let size = self.child.layout(ctx, bc, data, env);
+        self.child.set_origin(ctx, data, env, Point::ORIGIN);
+        ctx.set_paint_insets(self.child.paint_insets());
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        if self.opacity <= 0.0 {
+            return;
+        }
+        self.child.paint(ctx, data, env);
+
+        if self.opacity < 1.0 {
+            cover_with_background(ctx, self.child.paint_rect(), 1.0 - self.opacity, env);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::SizedBox;
+
+    #[test]
+    fn opacity_is_clamped() {
+        let opacity = |value| FadeToBackground::<(), _>::new(SizedBox::empty(), value).opacity();
+        assert_eq!(opacity(0.5), 0.5);
+        assert_eq!(opacity(2.0), 1.0);
+        assert_eq!(opacity(-1.0), 0.0);
+        assert_eq!(opacity(f64::NAN), 1.0);
+
+        let mut widget = FadeToBackground::<(), _>::new(SizedBox::empty(), 1.0);
+        widget.set_opacity(-0.5);
+        assert_eq!(widget.opacity(), 0.0);
+    }
+}
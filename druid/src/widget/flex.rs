@@ -16,7 +16,7 @@
 
 use crate::kurbo::common::FloatExt;
 use crate::widget::prelude::*;
-use crate::widget::SizedBox;
+use crate::widget::Spacer;
 use crate::{Data, KeyOrValue, Point, Rect, WidgetPod};
 
 /// A container with either horizontal or vertical layout.
@@ -93,7 +93,8 @@ use crate::{Data, KeyOrValue, Point, Rect, WidgetPod};
 /// - [`must_fill_main_axis`] determines whether the container is obliged to
 /// be maximally large on the major axis, as determined by its own constraints.
 /// If this is `true`, then the container must fill the available space on that
-/// axis; otherwise it may be smaller if its children are smaller.
+/// axis; otherwise it may be smaller if its children are smaller. This is
+/// useful when the container has a background, or sits in one.
 ///
 /// Additional options can be set (or overridden) in the [`FlexParams`].
 ///
@@ -140,6 +141,7 @@ pub struct Flex<T> {
     cross_alignment: CrossAxisAlignment,
     main_alignment: MainAxisAlignment,
     fill_major_axis: bool,
+    overflow: Overflow,
     children: Vec<ChildWidget<T>>,
 }
 
@@ -148,12 +150,6 @@ struct ChildWidget<T> {
     params: FlexParams,
 }
 
-/// A dummy widget we use to do spacing.
-struct Spacer {
-    axis: Axis,
-    len: KeyOrValue<f64>,
-}
-
 /// Optional parameters for an item in a [`Flex`] container (row or column).
 ///
 /// Generally, when you would like to add a flexible child to a container,
@@ -335,6 +331,40 @@ pub enum MainAxisAlignment {
     SpaceAround,
 }
 
+/// What a [`Flex`] container does with children that don't fit in it.
+///
+/// Either way, the parts of the children outside the container don't get
+/// any mouse events. To make them reachable, put the container in a
+/// [`Scroll`] instead, which gives it as much room as it needs on the
+/// scrolled axis:
+///
+/// ```
+/// use druid::widget::{Button, Flex, Scroll};
+///
+/// let toolbar = Scroll::new(
+///     Flex::row()
+///         .with_child(Button::<()>::new("Cut"))
+///         .with_child(Button::new("Copy"))
+///         .with_child(Button::new("Paste")),
+/// )
+/// .horizontal();
+/// ```
+///
+/// There is deliberately no `Overflow::Scroll`: scrolling needs a viewport,
+/// scroll bars and a scroll offset of its own, which is exactly what
+/// [`Scroll`] is, and a builder method on `Flex` can't wrap the container
+/// in another widget.
+///
+/// [`Flex`]: struct.Flex.html
+/// [`Scroll`]: struct.Scroll.html
+#[derive(Debug, Clone, Copy, PartialEq, Data)]
+pub enum Overflow {
+    /// Children are painted past the edges of the container.
+    Visible,
+    /// Children are clipped to the bounds of the container.
+    Clip,
+}
+
 impl FlexParams {
     /// Create custom `FlexParams` with a specific `flex_factor` and an optional
     /// [`CrossAxisAlignment`].
@@ -374,6 +404,7 @@ impl<T: Data> Flex<T> {
             cross_alignment: CrossAxisAlignment::Center,
             main_alignment: MainAxisAlignment::Start,
             fill_major_axis: false,
+            overflow: Overflow::Visible,
         }
     }
 
@@ -417,16 +448,30 @@ impl<T: Data> Flex<T> {
     /// or must expand to fill.
     ///
     /// If it expands, and there is extra space left over, that space is
-    /// distributed in accordance with the [`MainAxisAlignment`].
+    /// distributed in accordance with the [`MainAxisAlignment`]. If the main
+    /// axis is unbounded, for instance inside a [`Scroll`], there is no space
+    /// to fill, and the container shrinks to fit anyway.
     ///
     /// The default value is `false`.
     ///
     /// [`MainAxisAlignment`]: enum.MainAxisAlignment.html
+    /// [`Scroll`]: struct.Scroll.html
     pub fn must_fill_main_axis(mut self, fill: bool) -> Self {
         self.fill_major_axis = fill;
         self
     }
 
+    /// Builder-style method for setting what happens to children that don't
+    /// fit in the container.
+    ///
+    /// The default value is [`Overflow::Visible`].
+    ///
+    /// [`Overflow::Visible`]: enum.Overflow.html#variant.Visible
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
     /// Builder-style variant of `add_child`.
     ///
     /// Convenient for assembling a group of widgets in a single expression.
@@ -516,6 +561,11 @@ impl<T: Data> Flex<T> {
         self.fill_major_axis = fill;
     }
 
+    /// Set what happens to children that don't fit in the container.
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.overflow = overflow;
+    }
+
     /// Add a non-flex child widget.
     ///
     /// See also [`with_child`].
@@ -578,20 +628,14 @@ impl<T: Data> Flex<T> {
     ///
     /// [`add_default_spacer`]: #method.add_default_spacer
     pub fn add_spacer(&mut self, len: impl Into<KeyOrValue<f64>>) {
-        let spacer = Spacer {
-            axis: self.direction,
-            len: len.into(),
-        };
+        let spacer = Spacer::fixed(len).axis(self.direction);
         self.add_flex_child(spacer, 0.0);
     }
 
     /// Add an empty spacer widget with a specific `flex` factor.
     pub fn add_flex_spacer(&mut self, flex: f64) {
-        let child = match self.direction {
-            Axis::Vertical => SizedBox::empty().expand_height(),
-            Axis::Horizontal => SizedBox::empty().expand_width(),
-        };
-        self.add_flex_child(child, flex);
+        let spacer = Spacer::flex().axis(self.direction);
+        self.add_flex_child(spacer, flex);
     }
 }
 
@@ -629,7 +673,7 @@ impl<T: Data> Widget<T> for Flex<T> {
         // Measure non-flex children.
         let mut major_non_flex = 0.0;
         for child in &mut self.children {
-            any_use_baseline &= child.params.alignment == Some(CrossAxisAlignment::Baseline);
+            any_use_baseline |= child.params.alignment == Some(CrossAxisAlignment::Baseline);
 
             if child.params.flex == 0.0 {
                 let child_bc = self
@@ -654,6 +698,8 @@ impl<T: Data> Widget<T> for Flex<T> {
         }
 
         let total_major = self.direction.major(bc.max());
+        // there is nothing to fill if we are unbounded
+        let fill_major_axis = self.fill_major_axis && total_major.is_finite();
         let remaining = (total_major - major_non_flex).max(0.0);
         let mut remainder: f64 = 0.0;
         let flex_sum: f64 = self.children.iter().map(|child| child.params.flex).sum();
@@ -681,7 +727,7 @@ impl<T: Data> Widget<T> for Flex<T> {
         }
 
         // figure out if we have extra space on major axis, and if so how to use it
-        let extra = if self.fill_major_axis {
+        let extra = if fill_major_axis {
             (remaining - major_flex).max(0.0)
         } else {
             // if we are *not* expected to fill our available space this usually
@@ -704,8 +750,6 @@ impl<T: Data> Widget<T> for Flex<T> {
             let child_size = child.widget.layout_rect().size();
             let alignment = child.params.alignment.unwrap_or(self.cross_alignment);
             let child_minor_offset = match alignment {
-                // This will ignore baseline alignment if it is overridden on children,
-                // but is not the default for the container. Is this okay?
                 CrossAxisAlignment::Baseline if matches!(self.direction, Axis::Horizontal) => {
                     let extra_height = minor - minor_dim.min(minor);
                     let child_baseline = child.widget.baseline_offset();
@@ -736,7 +780,7 @@ impl<T: Data> Widget<T> for Flex<T> {
         let my_size: Size = self.direction.pack(major, minor_dim).into();
 
         // if we don't have to fill the main axis, we loosen that axis before constraining
-        let my_size = if !self.fill_major_axis {
+        let my_size = if !fill_major_axis {
             let max_major = self.direction.major(bc.max());
             self.direction
                 .constraints(bc, 0.0, max_major)
@@ -745,9 +789,11 @@ impl<T: Data> Widget<T> for Flex<T> {
             bc.constrain(my_size)
         };
 
-        let my_bounds = Rect::ZERO.with_size(my_size);
-        let insets = child_paint_rect - my_bounds;
-        ctx.set_paint_insets(insets);
+        if self.overflow == Overflow::Visible {
+            let my_bounds = Rect::ZERO.with_size(my_size);
+            let insets = child_paint_rect - my_bounds;
+            ctx.set_paint_insets(insets);
+        }
 
         let baseline_offset = match self.direction {
             Axis::Horizontal => max_below_baseline,
@@ -768,8 +814,20 @@ impl<T: Data> Widget<T> for Flex<T> {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
-        for child in &mut self.children {
-            child.widget.paint(ctx, data, env);
+        let children = &mut self.children;
+        match self.overflow {
+            Overflow::Visible => {
+                for child in children {
+                    child.widget.paint(ctx, data, env);
+                }
+            }
+            Overflow::Clip => ctx.with_save(|ctx| {
+                let bounds = ctx.size().to_rect();
+                ctx.clip(bounds);
+                for child in children {
+                    child.widget.paint(ctx, data, env);
+                }
+            }),
         }
 
         // paint the baseline if we're debugging layout
@@ -892,17 +950,6 @@ impl Iterator for Spacing {
     }
 }
 
-impl<T: Data> Widget<T> for Spacer {
-    fn event(&mut self, _: &mut EventCtx, _: &Event, _: &mut T, _: &Env) {}
-    fn lifecycle(&mut self, _: &mut LifeCycleCtx, _: &LifeCycle, _: &T, _: &Env) {}
-    fn update(&mut self, _: &mut UpdateCtx, _: &T, _: &T, _: &Env) {}
-    fn layout(&mut self, _: &mut LayoutCtx, _: &BoxConstraints, _: &T, env: &Env) -> Size {
-        let major = self.len.resolve(env);
-        self.axis.pack(major, 0.0).into()
-    }
-    fn paint(&mut self, _: &mut PaintCtx, _: &T, _: &Env) {}
-}
-
 impl From<f64> for FlexParams {
     fn from(flex: f64) -> FlexParams {
         FlexParams {
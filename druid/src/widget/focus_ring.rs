@@ -0,0 +1,126 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that shows when its child has keyboard focus.
+
+use crate::widget::prelude::*;
+use crate::{theme, Insets, InternalLifeCycle, Point, WidgetPod};
+
+/// A widget that paints a ring around its child while the child, or one of
+/// its descendants, has focus.
+///
+/// Following platform conventions, the ring is only shown when the focus
+/// was moved with the keyboard, for instance with the tab key; clicking a
+/// widget to focus it doesn't show the ring. It is painted just outside the
+/// child's bounds, with [`theme::FOCUS_RING_COLOR`] and
+/// [`theme::FOCUS_RING_WIDTH`].
+///
+/// This is available as [`WidgetExt::focus_ring`] for convenience.
+///
+/// [`theme::FOCUS_RING_COLOR`]: ../theme/constant.FOCUS_RING_COLOR.html
+/// [`theme::FOCUS_RING_WIDTH`]: ../theme/constant.FOCUS_RING_WIDTH.html
+/// [`WidgetExt::focus_ring`]: ../trait.WidgetExt.html#method.focus_ring
+pub struct FocusRing<T, W> {
+    child: WidgetPod<T, W>,
+    /// `true` while the mouse is pressed on us, so that focus gained during
+    /// that time doesn't show the ring.
+    mouse_down: bool,
+    has_focus: bool,
+    show_ring: bool,
+}
+
+impl<T: Data, W: Widget<T>> FocusRing<T, W> {
+    /// Create a new `FocusRing` around `child`.
+    pub fn new(child: W) -> Self {
+        FocusRing {
+            child: WidgetPod::new(child),
+            mouse_down: false,
+            has_focus: false,
+            show_ring: false,
+        }
+    }
+
+    /// Returns `true` if the ring is currently shown.
+    pub fn is_showing_ring(&self) -> bool {
+        self.show_ring
+    }
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for FocusRing<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseDown(_) => self.mouse_down = true,
+            Event::MouseUp(_) => self.mouse_down = false,
+            _ => {}
+        }
+        self.child.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.child.lifecycle(ctx, event, data, env);
+
+        match event {
+            // a click moves the focus right after the mouse down, so this is
+            // how we tell it apart from the keyboard.
+            LifeCycle::Internal(InternalLifeCycle::RouteFocusChanged { .. }) => {
+                let has_focus = ctx.has_focus();
+                if has_focus != self.has_focus {
+                    self.has_focus = has_focus;
+                    self.show_ring = has_focus && !self.mouse_down;
+                    ctx.request_paint();
+                }
+                self.mouse_down = false;
+            }
+            // we might not get the mouse up if it happens somewhere else
+            LifeCycle::HotChanged(false) => self.mouse_down = false,
+            _ => {}
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        if ctx.env_key_changed(&theme::FOCUS_RING_WIDTH) {
+            ctx.request_layout();
+        }
+        if ctx.env_key_changed(&theme::FOCUS_RING_COLOR) {
+            ctx.request_paint();
+        }
+        self.child.update(ctx, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("FocusRing");
+        let size = self.child.layout(ctx, bc, data, env);
+        self.child.set_origin(ctx, data, env, Point::ORIGIN);
+
+        let child = self.child.paint_insets();
+        let ring = env.get(theme::FOCUS_RING_WIDTH);
+        ctx.set_paint_insets(Insets::new(
+            child.x0.max(ring),
+            child.y0.max(ring),
+            child.x1.max(ring),
+            child.y1.max(ring),
+        ));
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.child.paint(ctx, data, env);
+
+        if self.show_ring {
+            let width = env.get(theme::FOCUS_RING_WIDTH);
+            let ring = ctx.size().to_rect().inset(width / 2.0);
+            ctx.stroke(ring, &env.get(theme::FOCUS_RING_COLOR), width);
+        }
+    }
+}
@@ -0,0 +1,342 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that arranges its children in rows and columns.
+
+use crate::kurbo::{Point, Rect};
+use crate::widget::prelude::*;
+use crate::{Data, WidgetPod};
+
+/// The size of a row or column in a [`Grid`].
+///
+/// [`Grid`]: struct.Grid.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackSize {
+    /// A fixed size.
+    Fixed(f64),
+    /// As large as the largest child that sits only in this track.
+    ///
+    /// Children that span several tracks don't count.
+    Auto,
+    /// A share of the space left over by the other tracks, in proportion
+    /// to the flex factor; like a flex child in a [`Flex`].
+    ///
+    /// [`Flex`]: struct.Flex.html
+    Flex(f64),
+}
+
+/// A container that places its children in the cells of a grid.
+///
+/// The grid has a fixed number of rows and columns, each with a
+/// [`TrackSize`]. Every child is placed at a row and a column, and can span
+/// several of them; it is laid out to fill exactly the cells it covers, so
+/// wrap it in an [`Align`] if it should keep its own size. Cells can be
+/// empty, and children can overlap, in which case the ones added later are
+/// painted on top.
+///
+/// This is the widget to use when things have to line up in two
+/// directions, like a form with labels and fields.
+///
+/// ```
+/// use druid::widget::{Grid, Label, TextBox, TrackSize};
+/// use druid::{Data, Lens, WidgetExt};
+///
+/// #[derive(Clone, Data, Lens)]
+/// struct Person {
+///     name: String,
+///     email: String,
+/// }
+///
+/// let form = Grid::new(
+///     vec![TrackSize::Auto, TrackSize::Flex(1.0)],
+///     vec![TrackSize::Auto, TrackSize::Auto, TrackSize::Fixed(40.0)],
+/// )
+/// .with_spacing(8.0)
+/// .with_child(Label::new("Name"), 0, 0)
+/// .with_child(TextBox::new().lens(Person::name), 0, 1)
+/// .with_child(Label::new("Email"), 1, 0)
+/// .with_child(TextBox::new().lens(Person::email), 1, 1)
+/// .with_spanning_child(Label::new("Both fields are required.").center(), 2, 0, 1, 2);
+/// ```
+///
+/// [`TrackSize`]: enum.TrackSize.html
+/// [`Align`]: struct.Align.html
+pub struct Grid<T> {
+    columns: Vec<TrackSize>,
+    rows: Vec<TrackSize>,
+    spacing: f64,
+    children: Vec<GridChild<T>>,
+}
+
+struct GridChild<T> {
+    widget: WidgetPod<T, Box<dyn Widget<T>>>,
+    row: usize,
+    col: usize,
+    row_span: usize,
+    col_span: usize,
+}
+
+impl<T: Data> Grid<T> {
+    /// Create a new, empty grid with the given columns and rows.
+    pub fn new(
+        columns: impl IntoIterator<Item = TrackSize>,
+        rows: impl IntoIterator<Item = TrackSize>,
+    ) -> Self {
+        Grid {
+            columns: columns.into_iter().collect(),
+            rows: rows.into_iter().collect(),
+            spacing: 0.0,
+            children: Vec::new(),
+        }
+    }
+
+    /// Builder-style method for setting the space between rows and columns.
+    pub fn with_spacing(mut self, spacing: f64) -> Self {
+        self.set_spacing(spacing);
+        self
+    }
+
+    /// Builder-style method to add a child in the cell at `row` and `col`.
+    pub fn with_child(mut self, child: impl Widget<T> + 'static, row: usize, col: usize) -> Self {
+        self.add_child(child, row, col);
+        self
+    }
+
+    /// Builder-style method to add a child that covers `row_span` rows and
+    /// `col_span` columns, starting at `row` and `col`.
+    pub fn with_spanning_child(
+        mut self,
+        child: impl Widget<T> + 'static,
+        row: usize,
+        col: usize,
+        row_span: usize,
+        col_span: usize,
+    ) -> Self {
+        self.add_spanning_child(child, row, col, row_span, col_span);
+        self
+    }
+
+    /// Set the space between rows and columns.
+    ///
+    /// If you change this property, you are responsible for calling
+    /// [`request_layout`] to ensure the widget is updated.
+    ///
+    /// [`request_layout`]: ../struct.EventCtx.html#method.request_layout
+    pub fn set_spacing(&mut self, spacing: f64) {
+        self.spacing = spacing;
+    }
+
+    /// Add a child in the cell at `row` and `col`.
+    ///
+    /// See also [`with_child`].
+    ///
+    /// [`with_child`]: #method.with_child
+    pub fn add_child(&mut self, child: impl Widget<T> + 'static, row: usize, col: usize) {
+        self.add_spanning_child(child, row, col, 1, 1);
+    }
+
+    /// Add a child that covers `row_span` rows and `col_span` columns,
+    /// starting at `row` and `col`.
+    ///
+    /// The spans are clamped so that the child stays inside the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` or `col` is outside the grid, or a span is zero.
+    pub fn add_spanning_child(
+        &mut self,
+        child: impl Widget<T> + 'static,
+        row: usize,
+        col: usize,
+        row_span: usize,
+        col_span: usize,
+    ) {
+        assert!(
+            row < self.rows.len() && col < self.columns.len(),
+            "cell ({}, {}) is outside of a grid with {} rows and {} columns",
+            row,
+            col,
+            self.rows.len(),
+            self.columns.len()
+        );
+        assert!(row_span > 0 && col_span > 0, "grid spans must be positive");
+        self.children.push(GridChild {
+            widget: WidgetPod::new(Box::new(child)),
+            row,
+            col,
+            row_span: row_span.min(self.rows.len() - row),
+            col_span: col_span.min(self.columns.len() - col),
+        });
+    }
+}
+
+/// Compute the size of each track on one axis.
+///
+/// `auto` holds the content size of each track, which is only used for
+/// [`TrackSize::Auto`] tracks.
+fn resolve_tracks(tracks: &[TrackSize], auto: &[f64], available: f64, spacing: f64) -> Vec<f64> {
+    let mut sizes: Vec<f64> = tracks
+        .iter()
+        .zip(auto)
+        .map(|(track, auto)| match track {
+            TrackSize::Fixed(size) => *size,
+            TrackSize::Auto => *auto,
+            TrackSize::Flex(_) => 0.0,
+        })
+        .collect();
+
+    let flex_sum: f64 = tracks
+        .iter()
+        .map(|track| match track {
+            TrackSize::Flex(flex) => *flex,
+            _ => 0.0,
+        })
+        .sum();
+    if flex_sum > 0.0 {
+        if available.is_infinite() {
+            log::warn!("A track of Grid is flex, but Grid is unbounded.");
+            return sizes;
+        }
+        let gaps = spacing * tracks.len().saturating_sub(1) as f64;
+        let remaining = (available - gaps - sizes.iter().sum::<f64>()).max(0.0);
+        for (size, track) in sizes.iter_mut().zip(tracks) {
+            if let TrackSize::Flex(flex) = track {
+                *size = remaining * flex / flex_sum;
+            }
+        }
+    }
+    sizes
+}
+
+/// The offset of the start of each track, given their sizes.
+fn track_offsets(sizes: &[f64], spacing: f64) -> Vec<f64> {
+    let mut offset = 0.0;
+    sizes
+        .iter()
+        .map(|size| {
+            let start = offset;
+            offset += size + spacing;
+            start
+        })
+        .collect()
+}
+
+/// The extent of `span` tracks starting at `start`.
+fn span_extent(sizes: &[f64], start: usize, span: usize, spacing: f64) -> f64 {
+    let tracks = &sizes[start..start + span];
+    tracks.iter().sum::<f64>() + spacing * span.saturating_sub(1) as f64
+}
+
+impl<T: Data> Widget<T> for Grid<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        for child in &mut self.children {
+            child.widget.event(ctx, event, data, env);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        for child in &mut self.children {
+            child.widget.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        for child in &mut self.children {
+            child.widget.update(ctx, data, env);
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("Grid");
+
+        // measure the children that decide the size of an auto track
+        let mut auto_widths = vec![0.0f64; self.columns.len()];
+        let mut auto_heights = vec![0.0f64; self.rows.len()];
+        for child in &mut self.children {
+            let sizes_column = child.col_span == 1 && self.columns[child.col] == TrackSize::Auto;
+            let sizes_row = child.row_span == 1 && self.rows[child.row] == TrackSize::Auto;
+            if sizes_column || sizes_row {
+                let size = child.widget.layout(ctx, &bc.loosen(), data, env);
+                if sizes_column {
+                    auto_widths[child.col] = auto_widths[child.col].max(size.width);
+                }
+                if sizes_row {
+                    auto_heights[child.row] = auto_heights[child.row].max(size.height);
+                }
+            }
+        }
+
+        let widths = resolve_tracks(&self.columns, &auto_widths, bc.max().width, self.spacing);
+        let heights = resolve_tracks(&self.rows, &auto_heights, bc.max().height, self.spacing);
+        let xs = track_offsets(&widths, self.spacing);
+        let ys = track_offsets(&heights, self.spacing);
+
+        let mut paint_rect = Rect::ZERO;
+        for child in &mut self.children {
+            let cell = Size::new(
+                span_extent(&widths, child.col, child.col_span, self.spacing),
+                span_extent(&heights, child.row, child.row_span, self.spacing),
+            );
+            child
+                .widget
+                .layout(ctx, &BoxConstraints::tight(cell), data, env);
+            let origin = Point::new(xs[child.col], ys[child.row]);
+            child.widget.set_origin(ctx, data, env, origin);
+            paint_rect = paint_rect.union(child.widget.paint_rect());
+        }
+
+        let total = Size::new(
+            span_extent(&widths, 0, widths.len(), self.spacing),
+            span_extent(&heights, 0, heights.len(), self.spacing),
+        );
+        let my_size = bc.constrain(total);
+        ctx.set_paint_insets(paint_rect - my_size.to_rect());
+        my_size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        for child in &mut self.children {
+            child.widget.paint(ctx, data, env);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flex_tracks_share_the_rest() {
+        let tracks = [
+            TrackSize::Fixed(100.),
+            TrackSize::Flex(1.),
+            TrackSize::Flex(3.),
+        ];
+        let sizes = resolve_tracks(&tracks, &[0., 0., 0.], 520., 10.);
+        assert_eq!(sizes, vec![100., 100., 300.]);
+        assert_eq!(track_offsets(&sizes, 10.), vec![0., 110., 220.]);
+        assert_eq!(span_extent(&sizes, 1, 2, 10.), 410.);
+    }
+
+    #[test]
+    fn auto_tracks_fit_content() {
+        let tracks = [TrackSize::Auto, TrackSize::Flex(1.)];
+        let sizes = resolve_tracks(&tracks, &[40., 0.], 100., 0.);
+        assert_eq!(sizes, vec![40., 60.]);
+
+        // nothing to share out if we are unbounded
+        let sizes = resolve_tracks(&tracks, &[40., 0.], f64::INFINITY, 0.);
+        assert_eq!(sizes, vec![40., 0.]);
+    }
+}
@@ -82,12 +82,13 @@ pub struct Image {
 impl Image {
     /// Create an image drawing widget from an image buffer.
     ///
-    /// By default, the Image will scale to fit its box constraints ([`FillStrat::Fill`])
-    /// and will be scaled bilinearly ([`InterpolationMode::Bilinear`])
+    /// By default, the Image will be scaled to fit its box constraints while keeping its
+    /// aspect ratio ([`FillStrat::Contain`]) and will be scaled bilinearly
+    /// ([`InterpolationMode::Bilinear`])
     ///
     /// The underlying `ImageBuf` uses `Arc` for buffer data, making it cheap to clone.
     ///
-    /// [`FillStrat::Fill`]: crate::widget::FillStrat::Fill
+    /// [`FillStrat::Contain`]: crate::widget::FillStrat::Contain
     /// [`InterpolationMode::Bilinear`]: crate::piet::InterpolationMode::Bilinear
     #[inline]
     pub fn new(image_data: ImageBuf) -> Self {
@@ -184,12 +185,15 @@ impl<T: Data> Widget<T> for Image {
         // the image.
         let max = bc.max();
         let image_size = self.image_data.size();
-        if bc.is_width_bounded() && !bc.is_height_bounded() {
+        if image_size.is_empty() {
+            // there's no aspect ratio to keep, and nothing to draw
+            bc.min()
+        } else if bc.is_width_bounded() && !bc.is_height_bounded() {
             let ratio = max.width / image_size.width;
-            Size::new(max.width, ratio * image_size.height)
+            bc.constrain(Size::new(max.width, ratio * image_size.height))
         } else if bc.is_height_bounded() && !bc.is_width_bounded() {
             let ratio = max.height / image_size.height;
-            Size::new(ratio * image_size.width, max.height)
+            bc.constrain(Size::new(ratio * image_size.width, max.height))
         } else {
             bc.constrain(self.image_data.size())
         }
@@ -464,6 +468,26 @@ mod tests {
         })
     }
 
+    #[test]
+    fn empty_image_layout() {
+        use crate::{
+            tests::harness::Harness,
+            widget::{Container, Scroll},
+            WidgetExt, WidgetId,
+        };
+
+        let id_1 = WidgetId::next();
+        let image_widget =
+            Scroll::new(Container::new(Image::new(ImageBuf::empty())).with_id(id_1)).vertical();
+
+        Harness::create_simple(true, image_widget, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            let state = harness.get_state(id_1);
+            assert_eq!(state.layout_rect().size(), Size::ZERO);
+        })
+    }
+
     #[test]
     fn image_clip_area() {
         use crate::{tests::harness::Harness, WidgetId};
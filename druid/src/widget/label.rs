@@ -16,16 +16,22 @@
 
 use std::ops::{Deref, DerefMut};
 
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::piet::PietText;
 use crate::text::TextStorage;
 use crate::widget::prelude::*;
 use crate::{
     ArcStr, Color, Data, FontDescriptor, KeyOrValue, LocalizedString, Point, TextAlignment,
-    TextLayout,
+    TextLayout, UnitPoint, Vec2,
 };
 
 // added padding between the edges of the widget and the text.
 const LABEL_X_PADDING: f64 = 2.0;
 
+/// What we put at the end of text that was cut short.
+const ELLIPSIS: &str = "\u{2026}";
+
 /// A label that displays static or dynamic text.
 ///
 /// This type manages an inner [`RawLabel`], updating its text based on the
@@ -45,6 +51,15 @@ const LABEL_X_PADDING: f64 = 2.0;
 /// it has a [`draw_at`] method that allows the caller to easily draw the label's
 /// text at the desired position on screen.
 ///
+/// # Layout
+///
+/// A label asks to be the size of its text, laid out in its font, plus a
+/// couple of pixels of horizontal padding; this is then constrained to the
+/// [`BoxConstraints`] it is given. The font defaults to [`theme::UI_FONT`]
+/// from the [`Env`], so changing that key resizes every label that doesn't
+/// set its own font. A label with empty text has no text width, but is still
+/// one line tall, so it doesn't collapse while its text is missing.
+///
 /// # Examples
 ///
 /// Make a label to say something **very** important:
@@ -66,6 +81,8 @@ const LABEL_X_PADDING: f64 = 2.0;
 /// ```
 ///
 /// [`ArcStr`]: ../type.ArcStr.html
+/// [`BoxConstraints`]: ../struct.BoxConstraints.html
+/// [`theme::UI_FONT`]: ../theme/constant.UI_FONT.html
 /// [`Data`]: ../trait.Data.html
 /// [`Env`]: ../struct.Env.html
 /// [`RawLabel`]: struct.RawLabel.html
@@ -92,6 +109,9 @@ pub struct Label<T> {
 pub struct RawLabel<T> {
     layout: TextLayout<T>,
     line_break_mode: LineBreaking,
+    alignment: UnitPoint,
+    /// The truncated text, when ellipsizing and the text doesn't fit.
+    ellipsized: Option<TextLayout<ArcStr>>,
 }
 
 /// Options for handling lines that are too wide for the label.
@@ -103,6 +123,10 @@ pub enum LineBreaking {
     Clip,
     /// Lines overflow the label.
     Overflow,
+    /// Lines that are too wide are cut short, and end in an ellipsis ("…").
+    ///
+    /// If the label is too narrow for even the ellipsis, it is clipped.
+    Ellipsis,
 }
 
 /// The text for a [`Label`].
@@ -148,6 +172,8 @@ impl<T: TextStorage> RawLabel<T> {
         Self {
             layout: TextLayout::new(),
             line_break_mode: LineBreaking::Overflow,
+            ellipsized: None,
+            alignment: UnitPoint::TOP_LEFT,
         }
     }
 
@@ -200,6 +226,44 @@ impl<T: TextStorage> RawLabel<T> {
         self
     }
 
+    /// Builder-style method to set where the text is placed when the label
+    /// is larger than its text.
+    ///
+    /// The default is [`UnitPoint::TOP_LEFT`].
+    ///
+    /// [`UnitPoint::TOP_LEFT`]: ../struct.UnitPoint.html#associatedconstant.TOP_LEFT
+    pub fn align(mut self, alignment: UnitPoint) -> Self {
+        self.set_alignment(alignment);
+        self
+    }
+
+    /// Builder-style method to enable or disable word wrapping.
+    ///
+    /// A wrapped label breaks its lines at the maximum width it is given,
+    /// and is as tall as it needs to be to fit all of them. This is the same
+    /// as setting the [`LineBreaking`] to `WordWrap` (or `Overflow`, if
+    /// `wrap` is `false`).
+    ///
+    /// [`LineBreaking`]: enum.LineBreaking.html
+    pub fn wrapped(mut self, wrap: bool) -> Self {
+        self.set_wrapped(wrap);
+        self
+    }
+
+    /// Builder-style method to enable or disable truncating the text with
+    /// an ellipsis.
+    ///
+    /// When the label is narrower than its text, as much of the text as fits
+    /// is shown, followed by "…". This is the same as setting the
+    /// [`LineBreaking`] to `Ellipsis` (or `Overflow`, if `ellipsize` is
+    /// `false`).
+    ///
+    /// [`LineBreaking`]: enum.LineBreaking.html
+    pub fn ellipsize(mut self, ellipsize: bool) -> Self {
+        self.set_ellipsize(ellipsize);
+        self
+    }
+
     /// Set the text color.
     ///
     /// The argument can be either a `Color` or a [`Key<Color>`].
@@ -260,13 +324,95 @@ impl<T: TextStorage> RawLabel<T> {
         self.layout.set_text_alignment(alignment);
     }
 
+    /// Set where the text is placed when the label is larger than its text.
+    ///
+    /// If you change this property, you are responsible for calling
+    /// [`request_layout`] to ensure the label is updated.
+    ///
+    /// [`request_layout`]: ../struct.EventCtx.html#method.request_layout
+    pub fn set_alignment(&mut self, alignment: UnitPoint) {
+        self.alignment = alignment;
+    }
+
+    /// Enable or disable word wrapping.
+    ///
+    /// If you change this property, you are responsible for calling
+    /// [`request_layout`] to ensure the label is updated.
+    ///
+    /// [`request_layout`]: ../struct.EventCtx.html#method.request_layout
+    pub fn set_wrapped(&mut self, wrap: bool) {
+        self.line_break_mode = if wrap {
+            LineBreaking::WordWrap
+        } else {
+            LineBreaking::Overflow
+        };
+    }
+
+    /// Enable or disable truncating the text with an ellipsis.
+    ///
+    /// If you change this property, you are responsible for calling
+    /// [`request_layout`] to ensure the label is updated.
+    ///
+    /// [`request_layout`]: ../struct.EventCtx.html#method.request_layout
+    pub fn set_ellipsize(&mut self, ellipsize: bool) {
+        self.line_break_mode = if ellipsize {
+            LineBreaking::Ellipsis
+        } else {
+            LineBreaking::Overflow
+        };
+    }
+
+    /// The longest start of our text that fits in `width` when followed by
+    /// an ellipsis, or just the ellipsis if not even that fits.
+    fn ellipsize_to(&self, factory: &mut PietText, env: &Env, width: f64) -> TextLayout<ArcStr> {
+        let text = self.layout.text().map(|text| text.as_str()).unwrap_or("");
+        let mut truncate = |end: usize| {
+            let truncated = format!("{}{}", text[..end].trim_end(), ELLIPSIS);
+            let mut layout = self.layout.restyled(ArcStr::from(truncated));
+            layout.rebuild_if_needed(factory, env);
+            layout
+        };
+
+        // we can cut before any grapheme but the first; the text gets wider
+        // the more of it we keep, so we can search for the longest that fits.
+        let ends: Vec<usize> = text.grapheme_indices(true).map(|(i, _)| i).collect();
+        let mut fitting = 0;
+        let (mut low, mut high) = (1, ends.len());
+        while low < high {
+            let mid = (low + high) / 2;
+            if truncate(ends[mid]).size().width <= width {
+                fitting = mid;
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        truncate(ends.get(fitting).copied().unwrap_or(0))
+    }
+
+    /// The position of the text in a label with the given size.
+    fn text_origin(&self, size: Size) -> Point {
+        let text_size = match &self.ellipsized {
+            Some(ellipsized) => ellipsized.size(),
+            None => self.layout.size(),
+        };
+        let extra_width = (size.width - text_size.width - LABEL_X_PADDING * 2.0).max(0.0);
+        let extra_height = (size.height - text_size.height).max(0.0);
+        self.alignment
+            .resolve(Size::new(extra_width, extra_height).to_rect())
+            + Vec2::new(LABEL_X_PADDING, 0.0)
+    }
+
     /// Draw this label's text at the provided `Point`, without internal padding.
     ///
     /// This is a convenience for widgets that want to use Label as a way
     /// of managing a dynamic or localized string, but want finer control
     /// over where the text is drawn.
     pub fn draw_at(&self, ctx: &mut PaintCtx, origin: impl Into<Point>) {
-        self.layout.draw(ctx, origin)
+        match &self.ellipsized {
+            Some(ellipsized) => ellipsized.draw(ctx, origin),
+            None => self.layout.draw(ctx, origin),
+        }
     }
 
     /// Return the offset of the first baseline relative to the bottom of the widget.
@@ -407,6 +553,36 @@ impl<T: Data> Label<T> {
         self
     }
 
+    /// Builder-style method to set where the text is placed when the label
+    /// is larger than its text.
+    ///
+    /// The default is [`UnitPoint::TOP_LEFT`].
+    ///
+    /// [`UnitPoint::TOP_LEFT`]: ../struct.UnitPoint.html#associatedconstant.TOP_LEFT
+    pub fn align(mut self, alignment: UnitPoint) -> Self {
+        self.label.set_alignment(alignment);
+        self
+    }
+
+    /// Builder-style method to enable or disable word wrapping.
+    ///
+    /// A wrapped label breaks its lines at the maximum width it is given,
+    /// and is as tall as it needs to be to fit all of them.
+    pub fn wrapped(mut self, wrap: bool) -> Self {
+        self.label.set_wrapped(wrap);
+        self
+    }
+
+    /// Builder-style method to enable or disable truncating the text with
+    /// an ellipsis.
+    ///
+    /// When the label is narrower than its text, as much of the text as fits
+    /// is shown, followed by "…".
+    pub fn ellipsize(mut self, ellipsize: bool) -> Self {
+        self.label.set_ellipsize(ellipsize);
+        self
+    }
+
     /// Draw this label's text at the provided `Point`, without internal padding.
     ///
     /// This is a convenience for widgets that want to use Label as a way
@@ -480,8 +656,8 @@ impl<T: Data> Widget<T> for Label<T> {
         if matches!(event, LifeCycle::WidgetAdded) {
             self.text.resolve(data, env);
             self.text_should_be_updated = false;
-            self.label
-                .lifecycle(ctx, event, &self.text.display_text(), env);
+            self.current_text = self.text.display_text();
+            self.label.lifecycle(ctx, event, &self.current_text, env);
         }
     }
 
@@ -539,19 +715,29 @@ impl<T: TextStorage> Widget<T> for RawLabel<T> {
         self.layout.set_wrap_width(width);
         self.layout.rebuild_if_needed(ctx.text(), env);
 
+        self.ellipsized = None;
+        if self.line_break_mode == LineBreaking::Ellipsis {
+            let available = bc.max().width - LABEL_X_PADDING * 2.0;
+            if self.layout.size().width > available {
+                self.ellipsized = Some(self.ellipsize_to(ctx.text(), env, available));
+            }
+        }
+
         let text_metrics = self.layout.layout_metrics();
-        ctx.set_baseline_offset(text_metrics.size.height - text_metrics.first_baseline);
-        bc.constrain(Size::new(
+        let size = bc.constrain(Size::new(
             text_metrics.size.width + 2. * LABEL_X_PADDING,
             text_metrics.size.height,
-        ))
+        ));
+        let text_origin = self.text_origin(size);
+        ctx.set_baseline_offset(size.height - text_origin.y - text_metrics.first_baseline);
+        size
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, _data: &T, _env: &Env) {
-        let origin = Point::new(LABEL_X_PADDING, 0.0);
         let label_size = ctx.size();
+        let origin = self.text_origin(label_size);
 
-        if self.line_break_mode == LineBreaking::Clip {
+        if self.line_break_mode == LineBreaking::Clip || self.ellipsized.is_some() {
             ctx.clip(label_size.to_rect());
         }
         self.draw_at(ctx, origin)
@@ -609,3 +795,157 @@ impl<T, F: Fn(&T, &Env) -> String + 'static> From<F> for LabelText<T> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::tests::harness::Harness;
+    use crate::tests::helpers::{data_setter, set_data};
+    use crate::widget::{Flex, WidgetExt};
+
+    /// Wraps a label, and records the text its layout has after each update,
+    /// and the ellipsized text after each layout.
+    struct TextProbe {
+        label: Label<u32>,
+        text: Rc<RefCell<Option<ArcStr>>>,
+        ellipsized: Rc<RefCell<Option<ArcStr>>>,
+    }
+
+    impl Widget<u32> for TextProbe {
+        fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut u32, env: &Env) {
+            self.label.event(ctx, event, data, env)
+        }
+
+        fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &u32, env: &Env) {
+            self.label.lifecycle(ctx, event, data, env)
+        }
+
+        fn update(&mut self, ctx: &mut UpdateCtx, old_data: &u32, data: &u32, env: &Env) {
+            self.label.update(ctx, old_data, data, env);
+            *self.text.borrow_mut() = self.label.label.layout.text().cloned();
+        }
+
+        fn layout(
+            &mut self,
+            ctx: &mut LayoutCtx,
+            bc: &BoxConstraints,
+            data: &u32,
+            env: &Env,
+        ) -> Size {
+            let size = self.label.layout(ctx, bc, data, env);
+            *self.ellipsized.borrow_mut() = self
+                .label
+                .label
+                .ellipsized
+                .as_ref()
+                .and_then(|layout| layout.text().cloned());
+            size
+        }
+
+        fn paint(&mut self, ctx: &mut PaintCtx, data: &u32, env: &Env) {
+            self.label.paint(ctx, data, env)
+        }
+    }
+
+    #[test]
+    fn dynamic_text_follows_data() {
+        let text: Rc<RefCell<Option<ArcStr>>> = Default::default();
+        let label = Label::dynamic(|data: &u32, _| match data {
+            0 => String::new(),
+            n => n.to_string(),
+        });
+        let probe = TextProbe {
+            label,
+            text: text.clone(),
+            ellipsized: Default::default(),
+        };
+
+        let widget = Flex::row().with_child(data_setter()).with_child(probe);
+
+        Harness::create_simple(5, widget, |harness| {
+            harness.send_initial_events();
+            harness.submit_command(set_data(0u32));
+            assert_eq!(text.borrow().as_deref(), Some(""));
+
+            harness.submit_command(set_data(6u32));
+            assert_eq!(text.borrow().as_deref(), Some("6"));
+        });
+    }
+
+    #[test]
+    fn empty_label_is_one_line_tall() {
+        let (empty_id, text_id) = (WidgetId::next(), WidgetId::next());
+        let widget = Flex::row()
+            .with_child(Label::new("").with_id(empty_id))
+            .with_child(Label::new("text").with_id(text_id));
+
+        Harness::create_simple((), widget, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            let empty = harness.get_state(empty_id).layout_rect();
+            let text = harness.get_state(text_id).layout_rect();
+            assert_eq!(empty.width(), LABEL_X_PADDING * 2.);
+            assert!(text.width() > empty.width());
+            assert!(empty.height() > 0.);
+            assert_eq!(empty.height(), text.height());
+        });
+    }
+
+    #[test]
+    fn ellipsis_fits_the_width() {
+        const TEXT: &str = "a label with some text that is a bit long";
+        let ellipsized_at = |width: f64| {
+            let ellipsized: Rc<RefCell<Option<ArcStr>>> = Default::default();
+            let probe = TextProbe {
+                label: Label::new(TEXT).ellipsize(true),
+                text: Default::default(),
+                ellipsized: ellipsized.clone(),
+            };
+            let id = WidgetId::next();
+            Harness::create_simple(0, probe.with_id(id).fix_width(width).center(), |harness| {
+                harness.send_initial_events();
+                harness.just_layout();
+                assert_eq!(harness.get_state(id).layout_rect().width(), width);
+            });
+            let text = ellipsized.borrow().clone();
+            text
+        };
+
+        // there is room for everything
+        assert_eq!(ellipsized_at(400.), None);
+
+        let text = ellipsized_at(80.).unwrap();
+        assert!(text.ends_with(ELLIPSIS));
+        assert!(TEXT.starts_with(text.trim_end_matches(ELLIPSIS)));
+        assert!(text.len() < TEXT.len());
+
+        // not even the ellipsis fits
+        assert_eq!(ellipsized_at(1.).as_deref(), Some(ELLIPSIS));
+    }
+
+    #[test]
+    fn wrapped_label_grows_taller() {
+        const TEXT: &str = "a long line of text that will not fit";
+        let (single_id, wrapped_id) = (WidgetId::next(), WidgetId::next());
+        let widget = Flex::row()
+            .with_child(Label::new(TEXT).fix_width(60.).with_id(single_id))
+            .with_child(
+                Label::new(TEXT)
+                    .wrapped(true)
+                    .fix_width(60.)
+                    .with_id(wrapped_id),
+            );
+
+        Harness::create_simple((), widget, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            let single = harness.get_state(single_id).layout_rect();
+            let wrapped = harness.get_state(wrapped_id).layout_rect();
+            assert_eq!(wrapped.width(), 60.);
+            assert!(wrapped.height() > single.height() * 2.);
+        });
+    }
+}
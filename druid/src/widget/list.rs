@@ -255,6 +255,10 @@ impl<C: Data, T: ListIter<C>> Widget<T> for List<C> {
         if self.update_child_count(data, env) {
             ctx.children_changed();
         }
+
+        if ctx.env_key_changed(&self.spacing) {
+            ctx.request_layout();
+        }
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
@@ -281,7 +285,9 @@ impl<C: Data, T: ListIter<C>> Widget<T> for List<C> {
         });
 
         // correct overshoot at end.
-        major_pos -= spacing;
+        if !self.children.is_empty() {
+            major_pos -= spacing;
+        }
 
         let my_size = bc.constrain(Size::from(axis.pack(major_pos, minor)));
         let insets = paint_rect - my_size.to_rect();
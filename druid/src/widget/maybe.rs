@@ -0,0 +1,138 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget for optional data.
+
+use crate::widget::prelude::*;
+use crate::widget::SizedBox;
+use crate::{Data, Point, WidgetPod};
+
+/// A widget that shows one of two children, depending on whether its
+/// `Option` data is `Some` or `None`.
+///
+/// When the data is `Some`, the `some` child is shown and receives the inner
+/// value as its data. When it is `None`, the `none` child is shown; it has
+/// no data to work with, so its data is `()`.
+///
+/// Only the child that is currently shown receives events, lifecycle events
+/// and updates, because there is no data for the other one.
+///
+/// # Examples
+///
+/// ```
+/// use druid::widget::{Label, Maybe};
+///
+/// let name: Maybe<String> = Maybe::new(
+///     Label::dynamic(|name: &String, _env| format!("Hello, {}!", name)),
+///     Label::new("Nobody here."),
+/// );
+/// ```
+pub struct Maybe<T> {
+    some: WidgetPod<T, Box<dyn Widget<T>>>,
+    none: WidgetPod<(), Box<dyn Widget<()>>>,
+    is_some: bool,
+}
+
+impl<T: Data> Maybe<T> {
+    /// Create a new `Maybe` widget, showing `some` when the data is `Some`
+    /// and `none` when it is `None`.
+    pub fn new(some: impl Widget<T> + 'static, none: impl Widget<()> + 'static) -> Maybe<T> {
+        Maybe {
+            some: WidgetPod::new(some).boxed(),
+            none: WidgetPod::new(none).boxed(),
+            is_some: false,
+        }
+    }
+
+    /// Create a new `Maybe` widget that shows nothing when the data is `None`.
+    pub fn or_empty(some: impl Widget<T> + 'static) -> Maybe<T> {
+        Self::new(some, SizedBox::empty())
+    }
+}
+
+impl<T: Data> Widget<Option<T>> for Maybe<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut Option<T>, env: &Env) {
+        match data {
+            Some(inner) if self.is_some => self.some.event(ctx, event, inner, env),
+            None if !self.is_some => self.none.event(ctx, event, &mut (), env),
+            // the data has changed, and we will switch children in update.
+            _ => (),
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &Option<T>,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            self.is_some = data.is_some();
+        }
+
+        match data {
+            Some(inner) if self.is_some => self.some.lifecycle(ctx, event, inner, env),
+            None if !self.is_some => self.none.lifecycle(ctx, event, &(), env),
+            _ => (),
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &Option<T>, data: &Option<T>, env: &Env) {
+        if data.is_some() != self.is_some {
+            self.is_some = data.is_some();
+            // the newly shown child may not have been added yet, and the
+            // hidden one should no longer be registered.
+            ctx.children_changed();
+        }
+
+        match data {
+            Some(inner) if self.some.is_initialized() => self.some.update(ctx, inner, env),
+            None if self.none.is_initialized() => self.none.update(ctx, &(), env),
+            _ => (),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        data: &Option<T>,
+        env: &Env,
+    ) -> Size {
+        bc.debug_check("Maybe");
+
+        match data {
+            Some(inner) => {
+                let size = self.some.layout(ctx, bc, inner, env);
+                self.some.set_origin(ctx, inner, env, Point::ORIGIN);
+                ctx.set_paint_insets(self.some.paint_insets());
+                size
+            }
+            None => {
+                let size = self.none.layout(ctx, bc, &(), env);
+                self.none.set_origin(ctx, &(), env, Point::ORIGIN);
+                ctx.set_paint_insets(self.none.paint_insets());
+                size
+            }
+        }
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &Option<T>, env: &Env) {
+        match data {
+            Some(inner) => self.some.paint(ctx, inner, env),
+            None => self.none.paint(ctx, &(), env),
+        }
+    }
+}
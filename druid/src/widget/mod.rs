@@ -20,17 +20,26 @@ mod checkbox;
 mod click;
 mod clip_box;
 mod common;
+mod constrained_box;
 mod container;
 mod controller;
+mod debounce;
+mod disabled_if;
+mod drag;
 mod either;
 mod env_scope;
+mod fade_to_background;
 mod flex;
+mod focus_ring;
+mod grid;
 mod identity_wrapper;
 mod image;
 mod invalidation;
 mod label;
 mod lens_wrap;
 mod list;
+mod maybe;
+mod on_added;
 mod padding;
 mod painter;
 mod parse;
@@ -38,10 +47,13 @@ mod progress_bar;
 mod radio;
 mod scope;
 mod scroll;
+mod shortcut;
 mod sized_box;
 mod slider;
+mod spacer;
 mod spinner;
 mod split;
+mod state_scope;
 mod stepper;
 #[cfg(feature = "svg")]
 #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
@@ -49,10 +61,13 @@ mod svg;
 mod switch;
 mod tabs;
 mod textbox;
+mod tooltip;
+mod transform;
 mod view_switcher;
 #[allow(clippy::module_inception)]
 mod widget;
 mod widget_ext;
+mod z_stack;
 
 pub use self::image::Image;
 pub use align::Align;
@@ -61,37 +76,52 @@ pub use checkbox::Checkbox;
 pub use click::Click;
 pub use clip_box::{ClipBox, Viewport};
 pub use common::FillStrat;
+pub use constrained_box::ConstrainedBox;
 pub use container::Container;
 pub use controller::{Controller, ControllerHost};
+pub use debounce::Debounce;
+pub use disabled_if::DisabledIf;
+pub use drag::{DragSource, DropTarget};
 pub use either::Either;
 pub use env_scope::EnvScope;
-pub use flex::{Axis, CrossAxisAlignment, Flex, FlexParams, MainAxisAlignment};
+pub use fade_to_background::FadeToBackground;
+pub use flex::{Axis, CrossAxisAlignment, Flex, FlexParams, MainAxisAlignment, Overflow};
+pub use focus_ring::FocusRing;
+pub use grid::{Grid, TrackSize};
 pub use identity_wrapper::IdentityWrapper;
 pub use label::{Label, LabelText, LineBreaking, RawLabel};
 pub use lens_wrap::LensWrap;
 pub use list::{List, ListIter};
+pub use maybe::Maybe;
+pub use on_added::OnAdded;
 pub use padding::Padding;
-pub use painter::{BackgroundBrush, Painter};
-pub use parse::Parse;
+pub use painter::{BackgroundBrush, Painter, ThemedGradient};
+pub use parse::{Parse, PARSE_IS_VALID};
 pub use progress_bar::ProgressBar;
 pub use radio::{Radio, RadioGroup};
 pub use scope::{DefaultScopePolicy, LensScopeTransfer, Scope, ScopePolicy, ScopeTransfer};
 pub use scroll::Scroll;
+pub use shortcut::Shortcut;
 pub use sized_box::SizedBox;
-pub use slider::Slider;
+pub use slider::{RangeSlider, Slider};
+pub use spacer::Spacer;
 pub use spinner::Spinner;
 pub use split::Split;
+pub use state_scope::{InteractionState, StateScope};
 pub use stepper::Stepper;
 #[cfg(feature = "svg")]
 pub use svg::{Svg, SvgData};
 pub use switch::Switch;
 pub use tabs::{TabInfo, Tabs, TabsEdge, TabsPolicy, TabsState, TabsTransition};
 pub use textbox::{TextBox, TextBoxEvent, ValidationDelegate, ValueTextBox};
+pub use tooltip::Tooltip;
+pub use transform::Transform;
 pub use view_switcher::ViewSwitcher;
 #[doc(hidden)]
 pub use widget::{Widget, WidgetId};
 #[doc(hidden)]
 pub use widget_ext::WidgetExt;
+pub use z_stack::ZStack;
 
 /// The types required to implement a `Widget`.
 ///
@@ -0,0 +1,120 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [`Controller`] that runs a closure when its widget is added.
+//!
+//! [`Controller`]: struct.Controller.html
+
+use crate::widget::Controller;
+use crate::{Data, Env, Event, EventCtx, LifeCycle, LifeCycleCtx, Selector, Widget};
+
+/// Sent to ourselves on [`LifeCycle::WidgetAdded`], because the data can
+/// only be changed from `event`.
+const RUN_ON_ADDED: Selector = Selector::new("druid.builtin.run-on-added");
+
+/// A [`Controller`] that calls a closure once, right after its child is
+/// added to the widget tree. It is available as an `on_added` method via
+/// [`WidgetExt`].
+///
+/// This is the place for one-time initialization, like focusing a widget,
+/// submitting a command to start loading something, or filling in the data.
+/// The closure runs in [`event`], shortly after [`LifeCycle::WidgetAdded`],
+/// so that it can change the data. Like the closure of [`Click`], it is also
+/// given the [`EventCtx`], which is needed to request focus or to submit a
+/// command.
+///
+/// ```
+/// use druid::widget::TextBox;
+/// use druid::WidgetExt;
+///
+/// let search = TextBox::<String>::new().on_added(|ctx, _data, _env| ctx.request_focus());
+/// ```
+///
+/// [`Controller`]: trait.Controller.html
+/// [`Click`]: struct.Click.html
+/// [`EventCtx`]: ../struct.EventCtx.html
+/// [`WidgetExt`]: ../trait.WidgetExt.html
+/// [`event`]: ../trait.Widget.html#tymethod.event
+/// [`LifeCycle::WidgetAdded`]: ../enum.LifeCycle.html#variant.WidgetAdded
+pub struct OnAdded<T> {
+    action: Box<dyn Fn(&mut EventCtx, &mut T, &Env)>,
+    has_run: bool,
+}
+
+impl<T: Data> OnAdded<T> {
+    /// Create a new `OnAdded` controller, calling `action` once the child
+    /// is added.
+    pub fn new(action: impl Fn(&mut EventCtx, &mut T, &Env) + 'static) -> Self {
+        OnAdded {
+            action: Box::new(action),
+            has_run: false,
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> Controller<T, W> for OnAdded<T> {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::Command(cmd) = event {
+            if cmd.is(RUN_ON_ADDED) && !self.has_run {
+                self.has_run = true;
+                (self.action)(ctx, data, env);
+            }
+        }
+        // the command is passed on, because other `OnAdded` controllers on
+        // the same widget send theirs to the same id.
+        child.event(ctx, event, data, env)
+    }
+
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &T,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            if !self.has_run {
+                ctx.submit_command(RUN_ON_ADDED.to(ctx.widget_id()));
+            }
+        }
+        child.lifecycle(ctx, event, data, env)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::harness::Harness;
+    use crate::widget::{Label, WidgetExt};
+    use crate::WidgetId;
+
+    #[test]
+    fn runs_once() {
+        let id = WidgetId::next();
+        let widget = Label::new("hello")
+            .on_added(|_ctx, data: &mut u32, _env| *data += 1)
+            .with_id(id);
+
+        Harness::create_simple(0, widget, |harness| {
+            harness.send_initial_events();
+            harness.just_layout();
+            assert_eq!(*harness.data(), 1);
+
+            // a second request to run, like a repeated `WidgetAdded` would send
+            harness.submit_command(RUN_ON_ADDED.to(id));
+            assert_eq!(*harness.data(), 1);
+        });
+    }
+}
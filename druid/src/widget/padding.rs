@@ -67,6 +67,34 @@ impl<T> Padding<T> {
             child: WidgetPod::new(child).boxed(),
         }
     }
+
+    /// Create a new widget with the same padding on every side.
+    pub fn uniform(padding: f64, child: impl Widget<T> + 'static) -> Padding<T> {
+        Padding::new(Insets::uniform(padding), child)
+    }
+
+    /// Create a new widget with padding on the left and right, but not on the
+    /// top and bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use druid::widget::{Label, Padding};
+    /// use druid::kurbo::Insets;
+    ///
+    /// let _: Padding<()> = Padding::horizontal(10.0, Label::new("wide"));
+    /// // equivalent:
+    /// let _: Padding<()> = Padding::new(Insets::new(10.0, 0.0, 10.0, 0.0), Label::new("wide"));
+    /// ```
+    pub fn horizontal(padding: f64, child: impl Widget<T> + 'static) -> Padding<T> {
+        Padding::new(Insets::uniform_xy(padding, 0.0), child)
+    }
+
+    /// Create a new widget with padding on the top and bottom, but not on the
+    /// left and right.
+    pub fn vertical(padding: f64, child: impl Widget<T> + 'static) -> Padding<T> {
+        Padding::new(Insets::uniform_xy(0.0, padding), child)
+    }
 }
 
 impl<T: Data> Widget<T> for Padding<T> {
@@ -14,7 +14,7 @@
 
 use crate::piet::{FixedGradient, LinearGradient, PaintBrush, RadialGradient};
 use crate::widget::prelude::*;
-use crate::{Color, Data, Key};
+use crate::{Color, Data, Key, KeyOrValue, UnitPoint};
 
 /// A widget that only handles painting.
 ///
@@ -28,6 +28,12 @@ use crate::{Color, Data, Key};
 /// hot or active state changes) you will need to call [`request_paint`] further
 /// up the tree, perhaps in a [`Controller`] widget.
 ///
+/// **Handling events**
+///
+/// By default a `Painter` ignores all events. If you need to respond to input
+/// (for instance, to make a custom control) you can provide a closure with
+/// [`on_event`].
+///
 /// # Examples
 ///
 /// Changing background color based on some part of data:
@@ -75,7 +81,11 @@ use crate::{Color, Data, Key};
 /// [`Data`]: ../trait.Data.html
 /// [`request_paint`]: ../EventCtx.html#method.request_paint
 /// [`Controller`]: trait.Controller.html
-pub struct Painter<T>(Box<dyn FnMut(&mut PaintCtx, &T, &Env)>);
+/// [`on_event`]: #method.on_event
+pub struct Painter<T> {
+    paint: Box<dyn FnMut(&mut PaintCtx, &T, &Env)>,
+    event: Option<Box<dyn FnMut(&mut EventCtx, &Event, &mut T, &Env)>>,
+}
 
 /// Something that can be used as the background for a widget.
 ///
@@ -93,15 +103,119 @@ pub enum BackgroundBrush<T> {
     Linear(LinearGradient),
     Radial(RadialGradient),
     Fixed(FixedGradient),
+    ThemedLinear(ThemedGradient),
     Painter(Painter<T>),
 }
 
+/// A linear gradient whose colors can be [`Key`]s, resolved in the [`Env`] at
+/// paint time.
+///
+/// This makes it possible to theme gradients like any other color: define
+/// keys for the stops, and set them in [`AppLauncher::configure_env`] or an
+/// [`EnvScope`]. The colors are spread evenly along the gradient.
+///
+/// ```
+/// use druid::widget::{Label, ThemedGradient};
+/// use druid::{theme, Color, KeyOrValue, UnitPoint, WidgetExt};
+///
+/// let gradient = ThemedGradient::new(
+///     UnitPoint::TOP,
+///     UnitPoint::BOTTOM,
+///     vec![KeyOrValue::from(theme::BUTTON_LIGHT), Color::BLACK.into()],
+/// );
+/// let banner = Label::<()>::new("Welcome").background(gradient);
+/// ```
+///
+/// [`Key`]: ../struct.Key.html
+/// [`Env`]: ../struct.Env.html
+/// [`AppLauncher::configure_env`]: ../struct.AppLauncher.html#method.configure_env
+/// [`EnvScope`]: struct.EnvScope.html
+#[derive(Clone, Debug)]
+pub struct ThemedGradient {
+    start: UnitPoint,
+    end: UnitPoint,
+    stops: Vec<KeyOrValue<Color>>,
+}
+
+impl ThemedGradient {
+    /// Create a gradient from `start` to `end`, through `stops`.
+    pub fn new(
+        start: UnitPoint,
+        end: UnitPoint,
+        stops: impl IntoIterator<Item = impl Into<KeyOrValue<Color>>>,
+    ) -> Self {
+        ThemedGradient {
+            start,
+            end,
+            stops: stops.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Create a gradient through `stops`, running across the center of the
+    /// painted area at `angle`.
+    ///
+    /// The angle is in radians, measured clockwise from the positive x axis,
+    /// so `0.0` runs from left to right and `PI / 2.0` from top to bottom.
+    pub fn with_angle(
+        angle: f64,
+        stops: impl IntoIterator<Item = impl Into<KeyOrValue<Color>>>,
+    ) -> Self {
+        let (dx, dy) = (angle.cos() / 2.0, angle.sin() / 2.0);
+        ThemedGradient::new(
+            UnitPoint::new(0.5 - dx, 0.5 - dy),
+            UnitPoint::new(0.5 + dx, 0.5 + dy),
+            stops,
+        )
+    }
+
+    /// Resolve the colors in `env`, to get a gradient that can be painted.
+    pub fn resolve(&self, env: &Env) -> LinearGradient {
+        let colors: Vec<Color> = self.stops.iter().map(|stop| stop.resolve(env)).collect();
+        LinearGradient::new(self.start, self.end, colors.as_slice())
+    }
+}
+
 impl<T> Painter<T> {
     /// Create a new `Painter` with the provided [`paint`] fn.
     ///
     /// [`paint`]: ../trait.Widget.html#tymethod.paint
     pub fn new(f: impl FnMut(&mut PaintCtx, &T, &Env) + 'static) -> Self {
-        Painter(Box::new(f))
+        Painter {
+            paint: Box::new(f),
+            event: None,
+        }
+    }
+
+    /// Builder-style method for providing an [`event`] fn.
+    ///
+    /// The closure is called for every event this widget receives.
+    ///
+    /// # Examples
+    ///
+    /// A square that counts how often it has been clicked:
+    ///
+    /// ```
+    /// use druid::{Event, RenderContext};
+    /// use druid::widget::Painter;
+    ///
+    /// let counter = Painter::new(|ctx, _data: &u32, env| {
+    ///     let bounds = ctx.size().to_rect();
+    ///     ctx.fill(bounds, &env.get(druid::theme::PRIMARY_DARK));
+    /// })
+    /// .on_event(|_ctx, event, data, _env| {
+    ///     if let Event::MouseDown(_) = event {
+    ///         *data += 1;
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// [`event`]: ../trait.Widget.html#tymethod.event
+    pub fn on_event(
+        mut self,
+        f: impl FnMut(&mut EventCtx, &Event, &mut T, &Env) + 'static,
+    ) -> Self {
+        self.event = Some(Box::new(f));
+        self
     }
 }
 
@@ -117,13 +231,27 @@ impl<T: Data> BackgroundBrush<T> {
             Self::Linear(grad) => ctx.fill(bounds, grad),
             Self::Radial(grad) => ctx.fill(bounds, grad),
             Self::Fixed(grad) => ctx.fill(bounds, grad),
+            Self::ThemedLinear(grad) => ctx.fill(bounds, &grad.resolve(env)),
             Self::Painter(painter) => painter.paint(ctx, data, env),
         }
     }
+
+    /// Returns `true` if this brush uses a key that changed in this update.
+    pub(crate) fn env_key_changed(&self, ctx: &UpdateCtx) -> bool {
+        match self {
+            Self::ColorKey(key) => ctx.env_key_changed(key),
+            Self::ThemedLinear(grad) => grad.stops.iter().any(|stop| ctx.env_key_changed(stop)),
+            _ => false,
+        }
+    }
 }
 
 impl<T: Data> Widget<T> for Painter<T> {
-    fn event(&mut self, _: &mut EventCtx, _: &Event, _: &mut T, _: &Env) {}
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Some(f) = self.event.as_mut() {
+            f(ctx, event, data, env)
+        }
+    }
     fn lifecycle(&mut self, _: &mut LifeCycleCtx, _: &LifeCycle, _: &T, _: &Env) {}
     fn update(&mut self, ctx: &mut UpdateCtx, old: &T, new: &T, _: &Env) {
         if !old.same(new) {
@@ -134,7 +262,7 @@ impl<T: Data> Widget<T> for Painter<T> {
         bc.max()
     }
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
-        (self.0)(ctx, data, env)
+        (self.paint)(ctx, data, env)
     }
 }
 
@@ -150,6 +278,15 @@ impl<T> From<Key<Color>> for BackgroundBrush<T> {
     }
 }
 
+impl<T> From<KeyOrValue<Color>> for BackgroundBrush<T> {
+    fn from(src: KeyOrValue<Color>) -> BackgroundBrush<T> {
+        match src {
+            KeyOrValue::Concrete(color) => BackgroundBrush::Color(color),
+            KeyOrValue::Key(key) => BackgroundBrush::ColorKey(key),
+        }
+    }
+}
+
 impl<T> From<LinearGradient> for BackgroundBrush<T> {
     fn from(src: LinearGradient) -> BackgroundBrush<T> {
         BackgroundBrush::Linear(src)
@@ -168,6 +305,12 @@ impl<T> From<FixedGradient> for BackgroundBrush<T> {
     }
 }
 
+impl<T> From<ThemedGradient> for BackgroundBrush<T> {
+    fn from(src: ThemedGradient) -> BackgroundBrush<T> {
+        BackgroundBrush::ThemedLinear(src)
+    }
+}
+
 impl<T> From<Painter<T>> for BackgroundBrush<T> {
     fn from(src: Painter<T>) -> BackgroundBrush<T> {
         BackgroundBrush::Painter(src)
@@ -184,3 +327,28 @@ impl<T> From<PaintBrush> for BackgroundBrush<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{theme, Point, Rect};
+
+    #[test]
+    fn key_or_value_brush() {
+        let brush: BackgroundBrush<()> = KeyOrValue::from(Color::WHITE).into();
+        assert!(matches!(brush, BackgroundBrush::Color(color) if color == Color::WHITE));
+
+        let brush: BackgroundBrush<()> = KeyOrValue::from(theme::PRIMARY_DARK).into();
+        assert!(matches!(brush, BackgroundBrush::ColorKey(key) if key == theme::PRIMARY_DARK));
+    }
+
+    #[test]
+    fn themed_gradient_angle() {
+        let stops: Vec<KeyOrValue<Color>> = vec![Color::WHITE.into(), theme::PRIMARY_DARK.into()];
+        let gradient = ThemedGradient::with_angle(std::f64::consts::FRAC_PI_2, stops);
+        let rect = Rect::new(0., 0., 10., 10.);
+        let (start, end) = (gradient.start.resolve(rect), gradient.end.resolve(rect));
+        assert!((start - Point::new(5., 0.)).hypot() < 1e-9);
+        assert!((end - Point::new(5., 10.)).hypot() < 1e-9);
+    }
+}
@@ -17,28 +17,136 @@ use std::mem;
 use std::str::FromStr;
 
 use crate::widget::prelude::*;
-use crate::Data;
+use crate::{Data, Key};
+
+/// Whether the text in the widget wrapped by a [`Parse`] currently parses.
+///
+/// `Parse` sets this in the [`Env`] it passes to its child.
+///
+/// [`Parse`]: struct.Parse.html
+/// [`Env`]: ../struct.Env.html
+pub const PARSE_IS_VALID: Key<bool> = Key::new("druid-builtin.parse.is-valid");
 
 /// Converts a `Widget<String>` to a `Widget<Option<T>>`, mapping parse errors to None
-pub struct Parse<T> {
-    widget: T,
+///
+/// `Parse` remembers whether the current string could be parsed. You can
+/// ask it with [`is_valid`], and the wrapped widget can find out from the
+/// [`PARSE_IS_VALID`] key in its [`Env`]; for instance, to paint a red
+/// border while the text is invalid:
+///
+/// ```
+/// use druid::widget::{Painter, Parse, TextBox, PARSE_IS_VALID};
+/// use druid::{Color, RenderContext, WidgetExt};
+///
+/// let input = TextBox::new().background(Painter::new(|ctx, _: &String, env| {
+///     if !env.try_get(PARSE_IS_VALID).unwrap_or(true) {
+///         let rect = ctx.size().to_rect().inset(-0.5);
+///         ctx.stroke(rect, &Color::rgb8(0xd0, 0x20, 0x20), 1.0);
+///     }
+/// }));
+/// let parse = Parse::new(input);
+/// # let _: &dyn druid::Widget<Option<f64>> = &parse;
+/// ```
+///
+/// By default the value is parsed with [`FromStr`] and shown with
+/// [`Display`]; use [`Parse::with`] to provide your own functions.
+///
+/// [`is_valid`]: #method.is_valid
+/// [`PARSE_IS_VALID`]: constant.PARSE_IS_VALID.html
+/// [`Env`]: ../struct.Env.html
+/// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+/// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+/// [`Parse::with`]: #method.with
+pub struct Parse<W, T> {
+    widget: W,
     state: String,
+    valid: bool,
+    // the last env we were given, and the env we made from it for the child
+    env_cache: Option<(Env, Env)>,
+    parse: Box<dyn Fn(&str) -> Option<T>>,
+    format: Box<dyn Fn(&T) -> String>,
 }
 
-impl<T> Parse<T> {
+impl<W, T: FromStr + Display + 'static> Parse<W, T> {
     /// Create a new `Parse` widget.
-    pub fn new(widget: T) -> Self {
-        Self {
+    pub fn new(widget: W) -> Self {
+        Parse::with(widget, str::parse, T::to_string)
+    }
+}
+
+impl<W, T> Parse<W, T> {
+    /// Create a new `Parse` widget with custom functions for converting
+    /// between the text and the value.
+    ///
+    /// `parse` is called whenever the child edits the text, and any error
+    /// is treated as invalid input. `format` is called to produce the text
+    /// when the value is set from outside.
+    ///
+    /// ```
+    /// use druid::widget::{Parse, TextBox};
+    ///
+    /// // a price, always shown with two decimals
+    /// let price = Parse::with(
+    ///     TextBox::new(),
+    ///     |s: &str| s.trim_start_matches('$').parse::<f64>(),
+    ///     |val: &f64| format!("${:.2}", val),
+    /// );
+    /// # let _: &dyn druid::Widget<Option<f64>> = &price;
+    /// ```
+    pub fn with<E>(
+        widget: W,
+        parse: impl Fn(&str) -> Result<T, E> + 'static,
+        format: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        Parse {
             widget,
             state: String::new(),
+            valid: true,
+            env_cache: None,
+            parse: Box::new(move |s| parse(s).ok()),
+            format: Box::new(format),
+        }
+    }
+
+    /// Returns `true` if the last text entered in the wrapped widget could
+    /// be parsed.
+    ///
+    /// This is also `true` while the text is empty, and whenever the data is
+    /// set to a value from outside.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    /// The env for the child, with [`PARSE_IS_VALID`] set.
+    ///
+    /// Adding a key copies the whole env, so this is only done again when
+    /// `env` or the validity changes.
+    ///
+    /// [`PARSE_IS_VALID`]: constant.PARSE_IS_VALID.html
+    fn child_env(&mut self, env: &Env) -> Env {
+        let is_current = match &self.env_cache {
+            Some((parent, child)) => parent.same(env) && child.get(PARSE_IS_VALID) == self.valid,
+            None => false,
+        };
+        if !is_current {
+            let child_env = env.clone().adding(PARSE_IS_VALID, self.valid);
+            self.env_cache = Some((env.clone(), child_env));
         }
+        self.env_cache.as_ref().unwrap().1.clone()
     }
 }
 
-impl<T: FromStr + Display + Data, W: Widget<String>> Widget<Option<T>> for Parse<W> {
+impl<T: Data, W: Widget<String>> Widget<Option<T>> for Parse<W, T> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut Option<T>, env: &Env) {
-        self.widget.event(ctx, event, &mut self.state, env);
-        *data = self.state.parse().ok();
+        let child_env = self.child_env(env);
+        self.widget.event(ctx, event, &mut self.state, &child_env);
+        *data = (self.parse)(&self.state);
+        // an empty input isn't an error, there's just nothing there yet
+        let valid = data.is_some() || self.state.is_empty();
+        if valid != self.valid {
+            self.valid = valid;
+            ctx.request_paint();
+        }
     }
 
     fn lifecycle(
@@ -50,18 +158,24 @@ impl<T: FromStr + Display + Data, W: Widget<String>> Widget<Option<T>> for Parse
     ) {
         if let LifeCycle::WidgetAdded = event {
             if let Some(data) = data {
-                self.state = data.to_string();
+                self.state = (self.format)(data);
             }
         }
-        self.widget.lifecycle(ctx, event, &self.state, env)
+        let child_env = self.child_env(env);
+        self.widget.lifecycle(ctx, event, &self.state, &child_env)
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &Option<T>, data: &Option<T>, env: &Env) {
         let old = match *data {
             None => return, // Don't clobber the input
-            Some(ref x) => mem::replace(&mut self.state, x.to_string()),
+            Some(ref x) => mem::replace(&mut self.state, (self.format)(x)),
         };
-        self.widget.update(ctx, &old, &self.state, env)
+        if !self.valid {
+            self.valid = true;
+            ctx.request_paint();
+        }
+        let child_env = self.child_env(env);
+        self.widget.update(ctx, &old, &self.state, &child_env)
     }
 
     fn layout(
@@ -71,11 +185,13 @@ impl<T: FromStr + Display + Data, W: Widget<String>> Widget<Option<T>> for Parse
         _data: &Option<T>,
         env: &Env,
     ) -> Size {
-        self.widget.layout(ctx, bc, &self.state, env)
+        let child_env = self.child_env(env);
+        self.widget.layout(ctx, bc, &self.state, &child_env)
     }
 
     fn paint(&mut self, paint: &mut PaintCtx, _data: &Option<T>, env: &Env) {
-        self.widget.paint(paint, &self.state, env)
+        let child_env = self.child_env(env);
+        self.widget.paint(paint, &self.state, &child_env)
     }
 
     fn id(&self) -> Option<WidgetId> {
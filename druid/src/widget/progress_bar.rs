@@ -15,11 +15,12 @@
 //! A progress bar widget.
 
 use crate::widget::prelude::*;
-use crate::{theme, LinearGradient, Point, Rect, UnitPoint};
+use crate::{theme, LinearGradient, Rect, UnitPoint};
 
 /// A progress bar, displaying a numeric progress value.
 ///
 /// This type impls `Widget<f64>`, expecting a float in the range `0.0..1.0`.
+/// Values outside of that range are clamped.
 #[derive(Debug, Clone, Default)]
 pub struct ProgressBar;
 
@@ -54,16 +55,12 @@ impl Widget<f64> for ProgressBar {
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &f64, env: &Env) {
-        let height = env.get(theme::BASIC_WIDGET_HEIGHT);
         let corner_radius = env.get(theme::PROGRESS_BAR_RADIUS);
         let clamped = data.max(0.0).min(1.0);
         let stroke_width = 2.0;
         let inset = -stroke_width / 2.0;
-        let size = ctx.size();
-        let rounded_rect = Size::new(size.width, height)
-            .to_rect()
-            .inset(inset)
-            .to_rounded_rect(corner_radius);
+        let track_rect = ctx.size().to_rect().inset(inset);
+        let rounded_rect = track_rect.to_rounded_rect(corner_radius);
 
         // Paint the border
         ctx.stroke(rounded_rect, &env.get(theme::BORDER_DARK), stroke_width);
@@ -79,14 +76,14 @@ impl Widget<f64> for ProgressBar {
         );
         ctx.fill(rounded_rect, &background_gradient);
 
-        // Paint the bar
-        let calculated_bar_width = clamped * rounded_rect.width();
+        // Paint the bar; it shares the track's origin so that a full bar
+        // exactly covers the track.
+        let calculated_bar_width = clamped * track_rect.width();
 
         let rounded_rect = Rect::from_origin_size(
-            Point::new(-inset, 0.),
-            Size::new(calculated_bar_width, height),
+            track_rect.origin(),
+            Size::new(calculated_bar_width, track_rect.height()),
         )
-        .inset((0.0, inset))
         .to_rounded_rect(corner_radius);
 
         let bar_gradient = LinearGradient::new(
@@ -89,7 +89,7 @@ impl<T: Data + PartialEq> Widget<T> for Radio<T> {
 
     fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
         self.child_label.update(ctx, old_data, data, env);
-        if !old_data.same(data) {
+        if !old_data.same(data) || ctx.env_key_changed(&theme::HIGH_CONTRAST) {
             ctx.request_paint();
         }
     }
@@ -105,7 +105,10 @@ impl<T: Data + PartialEq> Widget<T> for Radio<T> {
             label_size.width + radio_diam + x_padding,
             radio_diam.max(label_size.height),
         );
-        bc.constrain(desired_size)
+        let our_size = bc.constrain(desired_size);
+        let baseline = self.child_label.baseline_offset() + (our_size.height - label_size.height);
+        ctx.set_baseline_offset(baseline);
+        our_size
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
@@ -126,13 +129,9 @@ impl<T: Data + PartialEq> Widget<T> for Radio<T> {
 
         ctx.fill(circle, &background_gradient);
 
-        let border_color = if ctx.is_hot() {
-            env.get(theme::BORDER_LIGHT)
-        } else {
-            env.get(theme::BORDER_DARK)
-        };
+        let border_color = theme::border_color(env, ctx.is_hot());
 
-        ctx.stroke(circle, &border_color, 1.);
+        ctx.stroke(circle, &border_color, theme::border_width(env, 1.));
 
         // Check if data enum matches our variant
         if *data == self.variant {
@@ -14,9 +14,10 @@
 
 //! A container that scrolls its contents.
 
+use crate::commands::SCROLL_TO_VIEW;
 use crate::widget::prelude::*;
 use crate::widget::ClipBox;
-use crate::{scroll_component::*, Data, Rect, Vec2};
+use crate::{scroll_component::*, Data, Lens, Rect, Vec2};
 
 /// A container that scrolls its contents.
 ///
@@ -26,13 +27,44 @@ use crate::{scroll_component::*, Data, Rect, Vec2};
 /// The child is laid out with completely unconstrained layout bounds by
 /// default. Restrict to a specific axis with [`vertical`] or [`horizontal`].
 /// When restricted to scrolling on a specific axis the child's size is
-/// locked on the opposite axis.
+/// locked on the opposite axis. Use [`both`] to lift such a restriction.
 ///
+/// Descendants can ask to be scrolled into view with [`EventCtx::scroll_to`];
+/// a [`TextBox`] does this when it gains focus.
+///
+/// By default the scroll offset is internal state of the widget, and is lost
+/// when the widget is rebuilt. To keep it in your data instead, for instance
+/// to restore the position after navigating back to a view, provide a lens
+/// to it with [`offset_lens`].
+///
+/// [`offset_lens`]: #method.offset_lens
+/// [`EventCtx::scroll_to`]: ../struct.EventCtx.html#method.scroll_to
+/// [`TextBox`]: struct.TextBox.html
 /// [`vertical`]: struct.Scroll.html#method.vertical
 /// [`horizontal`]: struct.Scroll.html#method.horizontal
+/// [`both`]: struct.Scroll.html#method.both
 pub struct Scroll<T, W> {
     clip: ClipBox<T, W>,
     scroll_component: ScrollComponent,
+    offset_lens: Option<Box<dyn OffsetLens<T>>>,
+}
+
+/// A [`Lens`] to the scroll offset, as a trait object.
+///
+/// [`Lens`]: ../trait.Lens.html
+trait OffsetLens<T> {
+    fn get(&self, data: &T) -> Vec2;
+    fn put(&self, data: &mut T, offset: Vec2);
+}
+
+impl<T, L: Lens<T, Vec2>> OffsetLens<T> for L {
+    fn get(&self, data: &T) -> Vec2 {
+        self.with(data, |offset| *offset)
+    }
+
+    fn put(&self, data: &mut T, offset: Vec2) {
+        self.with_mut(data, |old| *old = offset)
+    }
 }
 
 impl<T, W: Widget<T>> Scroll<T, W> {
@@ -45,9 +77,37 @@ impl<T, W: Widget<T>> Scroll<T, W> {
         Scroll {
             clip: ClipBox::new(child),
             scroll_component: ScrollComponent::new(),
+            offset_lens: None,
         }
     }
 
+    /// Builder-style method to keep the scroll offset in the data, using
+    /// `lens`, instead of in the widget.
+    ///
+    /// Whenever the user scrolls, the new offset is written through the lens;
+    /// when the offset in the data is changed from elsewhere, the viewport
+    /// scrolls to match it.
+    ///
+    /// ```
+    /// use druid::widget::{Label, Scroll};
+    /// use druid::{Data, Lens, Vec2, Widget, WidgetExt};
+    ///
+    /// #[derive(Clone, Data, Lens)]
+    /// struct AppState {
+    ///     text: String,
+    ///     text_offset: Vec2,
+    /// }
+    ///
+    /// fn text_view() -> impl Widget<AppState> {
+    ///     let label = Label::dynamic(|text: &String, _env| text.clone()).lens(AppState::text);
+    ///     Scroll::new(label).vertical().offset_lens(AppState::text_offset)
+    /// }
+    /// ```
+    pub fn offset_lens(mut self, lens: impl Lens<T, Vec2> + 'static) -> Self {
+        self.offset_lens = Some(Box::new(lens));
+        self
+    }
+
     /// Restrict scrolling to the vertical axis while locking child width.
     pub fn vertical(mut self) -> Self {
         self.clip.set_constrain_vertical(false);
@@ -62,6 +122,19 @@ impl<T, W: Widget<T>> Scroll<T, W> {
         self
     }
 
+    /// Allow scrolling on both axes, leaving the child unconstrained.
+    ///
+    /// This is the default, and is only needed to undo a previous call
+    /// to [`vertical`] or [`horizontal`].
+    ///
+    /// [`vertical`]: #method.vertical
+    /// [`horizontal`]: #method.horizontal
+    pub fn both(mut self) -> Self {
+        self.clip.set_constrain_vertical(false);
+        self.clip.set_constrain_horizontal(false);
+        self
+    }
+
     /// Returns a reference to the child widget.
     pub fn child(&self) -> &W {
         self.clip.child()
@@ -96,10 +169,38 @@ impl<T, W: Widget<T>> Scroll<T, W> {
     pub fn scroll_to(&mut self, region: Rect) -> bool {
         self.clip.pan_to_visible(region)
     }
+
+    /// Write the current offset through the offset lens, if there is one.
+    fn write_offset(&self, data: &mut T) {
+        if let Some(lens) = &self.offset_lens {
+            let offset = self.offset();
+            if lens.get(data) != offset {
+                lens.put(data, offset);
+            }
+        }
+    }
 }
 
 impl<T: Data, W: Widget<T>> Widget<T> for Scroll<T, W> {
     fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        if let Event::Notification(note) = event {
+            if let Some(rect) = note.get(SCROLL_TO_VIEW) {
+                // the rect is relative to our viewport, not to our content
+                if self.scroll_to(*rect + self.offset()) {
+                    self.scroll_component
+                        .reset_scrollbar_fade(|d| ctx.request_timer(d), env);
+                    ctx.request_paint();
+                    self.write_offset(data);
+                }
+                ctx.set_handled();
+                return;
+            }
+        }
+
+        // only write the offset back if this event moved the viewport; the
+        // events that arrive before the first layout must not overwrite the
+        // offset in the data before we had a chance to restore it.
+        let old_offset = self.offset();
         let scroll_component = &mut self.scroll_component;
         self.clip.with_port(|port| {
             scroll_component.event(port, ctx, event, env);
@@ -111,6 +212,9 @@ impl<T: Data, W: Widget<T>> Widget<T> for Scroll<T, W> {
         self.clip.with_port(|port| {
             scroll_component.handle_scroll(port, ctx, event, env);
         });
+        if self.offset() != old_offset {
+            self.write_offset(data);
+        }
     }
 
     fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
@@ -119,6 +223,14 @@ impl<T: Data, W: Widget<T>> Widget<T> for Scroll<T, W> {
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if let Some(lens) = &self.offset_lens {
+            let offset = lens.get(data);
+            if offset != lens.get(old_data) && self.clip.pan_to(offset.to_point()) {
+                self.scroll_component
+                    .reset_scrollbar_fade(|d| ctx.request_timer(d), env);
+                ctx.request_paint();
+            }
+        }
         self.clip.update(ctx, old_data, data, env);
     }
 
@@ -131,8 +243,16 @@ impl<T: Data, W: Widget<T>> Widget<T> for Scroll<T, W> {
 
         let self_size = bc.constrain(child_size);
         // The new size might have made the current scroll offset invalid. This makes it valid
-        // again.
-        let _ = self.scroll_by(Vec2::ZERO);
+        // again. If the offset is in the data, that might not have been possible
+        // before the first layout, so we restore it here.
+        match &self.offset_lens {
+            Some(lens) => {
+                let _ = self.clip.pan_to(lens.get(data).to_point());
+            }
+            None => {
+                let _ = self.scroll_by(Vec2::ZERO);
+            }
+        }
         if old_size != self_size {
             self.scroll_component
                 .reset_scrollbar_fade(|d| ctx.request_timer(d), env);
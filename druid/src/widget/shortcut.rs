@@ -0,0 +1,73 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [`Controller`] that turns a keyboard shortcut into a [`Command`].
+//!
+//! [`Controller`]: struct.Controller.html
+//! [`Command`]: ../struct.Command.html
+
+use crate::widget::Controller;
+use crate::{Command, Env, Event, EventCtx, HotKey, Widget};
+
+/// A [`Controller`] that submits a [`Command`] when a [`HotKey`] is pressed.
+/// It is available as an `on_hotkey` method via [`WidgetExt`].
+///
+/// The key is matched before the event is passed on, so the child doesn't
+/// see the keys that trigger the command.
+///
+/// Key events are only delivered along the path to the focused widget; a
+/// shortcut works while its child, or one of the child's descendants, has
+/// focus. Put it around the root widget of a window to make it work
+/// anywhere in that window.
+///
+/// ```
+/// use druid::widget::{Flex, TextBox};
+/// use druid::{commands, HotKey, SysMods, WidgetExt};
+///
+/// let editor = Flex::column()
+///     .with_child(TextBox::new())
+///     .on_hotkey(HotKey::new(SysMods::Cmd, "s"), commands::SAVE_FILE);
+/// # let _: &dyn druid::Widget<String> = &editor;
+/// ```
+///
+/// [`Controller`]: trait.Controller.html
+/// [`Command`]: ../struct.Command.html
+/// [`HotKey`]: ../struct.HotKey.html
+/// [`WidgetExt`]: ../trait.WidgetExt.html
+pub struct Shortcut {
+    hotkey: HotKey,
+    command: Command,
+}
+
+impl Shortcut {
+    /// Create a new `Shortcut`, submitting `command` when `hotkey` is pressed.
+    pub fn new(hotkey: HotKey, command: impl Into<Command>) -> Self {
+        Shortcut {
+            hotkey,
+            command: command.into(),
+        }
+    }
+}
+
+impl<T, W: Widget<T>> Controller<T, W> for Shortcut {
+    fn event(&mut self, child: &mut W, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::KeyDown(key) if self.hotkey.matches(key) => {
+                ctx.submit_command(self.command.clone());
+                ctx.set_handled();
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+}
@@ -17,21 +17,42 @@
 use std::f64::INFINITY;
 
 use crate::widget::prelude::*;
-use crate::Data;
+use crate::{Data, KeyOrValue};
 
 /// A widget with predefined size.
 ///
 /// If given a child, this widget forces its child to have a specific width and/or height
 /// (assuming values are permitted by this widget's parent). If either the width or height is not set,
 /// this widget will size itself to match the child's size in that dimension.
+/// A `SizedBox` with a child and nothing set passes its constraints through
+/// unchanged, so it doesn't affect layout at all.
 ///
 /// If not given a child, SizedBox will try to size itself as close to the specified height
 /// and width as possible given the parent's constraints. If height or width is not set,
 /// it will be treated as zero.
+///
+/// Instead of a fixed size, a `SizedBox` can also be given a minimum and/or
+/// maximum width and height, which narrow the constraints passed to the child
+/// so that it can grow between a floor and a ceiling.
+///
+/// The width and height can be [`Key`]s, so that sizes can come from the
+/// theme; they are looked up in the [`Env`] during layout.
+///
+/// A `SizedBox` can also keep a fixed [aspect ratio], in which case it picks
+/// the largest size with that ratio that fits the constraints.
+///
+/// [`Key`]: ../struct.Key.html
+/// [`Env`]: ../struct.Env.html
+/// [aspect ratio]: #method.aspect_ratio
 pub struct SizedBox<T> {
     inner: Option<Box<dyn Widget<T>>>,
-    width: Option<f64>,
-    height: Option<f64>,
+    width: Option<KeyOrValue<f64>>,
+    height: Option<KeyOrValue<f64>>,
+    min_width: Option<f64>,
+    max_width: Option<f64>,
+    min_height: Option<f64>,
+    max_height: Option<f64>,
+    aspect_ratio: Option<f64>,
 }
 
 impl<T> SizedBox<T> {
@@ -41,6 +62,11 @@ impl<T> SizedBox<T> {
             inner: Some(Box::new(inner)),
             width: None,
             height: None,
+            min_width: None,
+            max_width: None,
+            min_height: None,
+            max_height: None,
+            aspect_ratio: None,
         }
     }
 
@@ -50,18 +76,63 @@ impl<T> SizedBox<T> {
             inner: None,
             width: None,
             height: None,
+            min_width: None,
+            max_width: None,
+            min_height: None,
+            max_height: None,
+            aspect_ratio: None,
         }
     }
 
     /// Set container's width.
-    pub fn width(mut self, width: f64) -> Self {
-        self.width = Some(width);
+    ///
+    /// The argument can be either an `f64` or a [`Key<f64>`].
+    ///
+    /// [`Key<f64>`]: ../struct.Key.html
+    pub fn width(mut self, width: impl Into<KeyOrValue<f64>>) -> Self {
+        self.width = Some(width.into());
         self
     }
 
     /// Set container's height.
-    pub fn height(mut self, height: f64) -> Self {
-        self.height = Some(height);
+    ///
+    /// The argument can be either an `f64` or a [`Key<f64>`].
+    ///
+    /// [`Key<f64>`]: ../struct.Key.html
+    pub fn height(mut self, height: impl Into<KeyOrValue<f64>>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    /// Set the minimum width of the container.
+    ///
+    /// The child is given at least this width, if the parent allows it.
+    pub fn min_width(mut self, width: f64) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    /// Set the maximum width of the container.
+    ///
+    /// The child is given at most this width, unless the parent requires more.
+    pub fn max_width(mut self, width: f64) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Set the minimum height of the container.
+    ///
+    /// The child is given at least this height, if the parent allows it.
+    pub fn min_height(mut self, height: f64) -> Self {
+        self.min_height = Some(height);
+        self
+    }
+
+    /// Set the maximum height of the container.
+    ///
+    /// The child is given at most this height, unless the parent requires more.
+    pub fn max_height(mut self, height: f64) -> Self {
+        self.max_height = Some(height);
         self
     }
 
@@ -74,8 +145,8 @@ impl<T> SizedBox<T> {
     /// [`expand_height`]: #method.expand_height
     /// [`expand_width`]: #method.expand_width
     pub fn expand(mut self) -> Self {
-        self.width = Some(INFINITY);
-        self.height = Some(INFINITY);
+        self.width = Some(INFINITY.into());
+        self.height = Some(INFINITY.into());
         self
     }
 
@@ -83,7 +154,7 @@ impl<T> SizedBox<T> {
     ///
     /// This will force the child to have maximum width.
     pub fn expand_width(mut self) -> Self {
-        self.width = Some(INFINITY);
+        self.width = Some(INFINITY.into());
         self
     }
 
@@ -91,28 +162,81 @@ impl<T> SizedBox<T> {
     ///
     /// This will force the child to have maximum height.
     pub fn expand_height(mut self) -> Self {
-        self.height = Some(INFINITY);
+        self.height = Some(INFINITY.into());
         self
     }
 
-    fn child_constraints(&self, bc: &BoxConstraints) -> BoxConstraints {
-        // if we don't have a width/height, we don't change that axis.
-        // if we have a width/height, we clamp it on that axis.
-        let (min_width, max_width) = match self.width {
-            Some(width) => {
-                let w = width.max(bc.min().width).min(bc.max().width);
-                (w, w)
-            }
-            None => (bc.min().width, bc.max().width),
-        };
+    /// Keep the ratio between width and height fixed.
+    ///
+    /// During layout the container picks the largest size for which
+    /// `width / height == ratio` that still fits within the constraints, taking
+    /// any width or height set on this container into account. Combined with
+    /// [`expand`], this fills as much of the available space as possible
+    /// without distorting the child.
+    ///
+    /// If the constraints are unbounded on both axes, the smallest size with
+    /// this ratio that satisfies the minimum constraints is used. If the
+    /// minimum constraints cannot be met with this ratio, they take
+    /// precedence.
+    ///
+    /// `ratio` must be positive and finite.
+    ///
+    /// [`expand`]: #method.expand
+    pub fn aspect_ratio(mut self, ratio: f64) -> Self {
+        debug_assert!(
+            ratio > 0.0 && ratio.is_finite(),
+            "invalid aspect ratio {}",
+            ratio
+        );
+        self.aspect_ratio = Some(ratio);
+        self
+    }
 
-        let (min_height, max_height) = match self.height {
-            Some(height) => {
-                let h = height.max(bc.min().height).min(bc.max().height);
-                (h, h)
+    fn child_constraints(&self, bc: &BoxConstraints, env: &Env) -> BoxConstraints {
+        let child_bc = self.fixed_size_constraints(bc, env);
+        match self.aspect_ratio {
+            // with an aspect ratio, a width or height (including `expand`)
+            // only limits how large we can get.
+            Some(ratio) => {
+                let available = BoxConstraints::new(bc.min(), child_bc.max());
+                BoxConstraints::tight(Self::fit_aspect_ratio(&available, ratio))
             }
-            None => (bc.min().height, bc.max().height),
+            None => child_bc,
+        }
+    }
+
+    /// The largest size with the given ratio that fits in `bc`.
+    fn fit_aspect_ratio(bc: &BoxConstraints, ratio: f64) -> Size {
+        let max = bc.max();
+        let width = match (max.width.is_finite(), max.height.is_finite()) {
+            (true, true) => max.width.min(max.height * ratio),
+            (true, false) => max.width,
+            (false, true) => max.height * ratio,
+            (false, false) => bc.min().width.max(bc.min().height * ratio),
         };
+        bc.constrain(Size::new(width, width / ratio))
+    }
+
+    /// The width and height, resolved in `env`.
+    fn size(&self, env: &Env) -> (Option<f64>, Option<f64>) {
+        let resolve = |size: &Option<KeyOrValue<f64>>| size.as_ref().map(|size| size.resolve(env));
+        (resolve(&self.width), resolve(&self.height))
+    }
+
+    fn fixed_size_constraints(&self, bc: &BoxConstraints, env: &Env) -> BoxConstraints {
+        let (width, height) = self.size(env);
+        let (min_width, max_width) = axis_constraints(
+            (bc.min().width, bc.max().width),
+            width,
+            self.min_width,
+            self.max_width,
+        );
+        let (min_height, max_height) = axis_constraints(
+            (bc.min().height, bc.max().height),
+            height,
+            self.min_height,
+            self.max_height,
+        );
 
         BoxConstraints::new(
             Size::new(min_width, min_height),
@@ -122,7 +246,30 @@ impl<T> SizedBox<T> {
 
     #[cfg(test)]
     pub(crate) fn width_and_height(&self) -> (Option<f64>, Option<f64>) {
-        (self.width, self.height)
+        self.size(&Env::default())
+    }
+}
+
+/// Compute the constraints on one axis.
+///
+/// The incoming `(min, max)` range is first narrowed by the `lower` and `upper`
+/// bounds, without leaving it; if we have a size, we then clamp it to that
+/// range, otherwise we don't change the axis any further.
+fn axis_constraints(
+    (min, max): (f64, f64),
+    size: Option<f64>,
+    lower: Option<f64>,
+    upper: Option<f64>,
+) -> (f64, f64) {
+    let clamp = |value: f64| value.max(min).min(max);
+    let lower = lower.map_or(min, clamp);
+    let upper = upper.map_or(max, clamp).max(lower);
+    match size {
+        Some(size) => {
+            let size = size.max(lower).min(upper);
+            (size, size)
+        }
+        None => (lower, upper),
     }
 }
 
@@ -140,6 +287,13 @@ impl<T: Data> Widget<T> for SizedBox<T> {
     }
 
     fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        let size_changed = |size: &Option<KeyOrValue<f64>>| {
+            size.as_ref()
+                .map_or(false, |size| ctx.env_key_changed(size))
+        };
+        if size_changed(&self.width) || size_changed(&self.height) {
+            ctx.request_layout();
+        }
         if let Some(ref mut inner) = self.inner {
             inner.update(ctx, old_data, data, env);
         }
@@ -148,10 +302,14 @@ impl<T: Data> Widget<T> for SizedBox<T> {
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
         bc.debug_check("SizedBox");
 
-        let child_bc = self.child_constraints(bc);
+        let child_bc = self.child_constraints(bc, env);
         let size = match self.inner.as_mut() {
             Some(inner) => inner.layout(ctx, &child_bc, data, env),
-            None => bc.constrain((self.width.unwrap_or(0.0), self.height.unwrap_or(0.0))),
+            None if self.aspect_ratio.is_some() => child_bc.min(),
+            None => {
+                let (width, height) = self.size(env);
+                child_bc.constrain((width.unwrap_or(0.0), height.unwrap_or(0.0)))
+            }
         };
 
         if size.width.is_infinite() {
@@ -180,21 +338,115 @@ impl<T: Data> Widget<T> for SizedBox<T> {
 mod tests {
     use super::*;
     use crate::widget::Label;
+    use crate::Key;
 
     #[test]
     fn expand() {
+        let env = Env::default();
         let expand = SizedBox::<()>::new(Label::new("hello!")).expand();
         let bc = BoxConstraints::tight(Size::new(400., 400.)).loosen();
-        let child_bc = expand.child_constraints(&bc);
+        let child_bc = expand.child_constraints(&bc, &env);
         assert_eq!(child_bc.min(), Size::new(400., 400.,));
     }
 
+    #[test]
+    fn unconfigured_is_transparent() {
+        let env = Env::default();
+        let transparent = SizedBox::<()>::new(Label::new("hello!"));
+        let loose = BoxConstraints::tight(Size::new(400., 400.)).loosen();
+        let bcs = [
+            loose,
+            BoxConstraints::tight(Size::new(150., 30.)),
+            BoxConstraints::new(Size::new(10., 10.), Size::new(INFINITY, INFINITY)),
+        ];
+        for bc in bcs.iter() {
+            assert_eq!(transparent.child_constraints(bc, &env), *bc);
+        }
+    }
+
     #[test]
     fn no_width() {
+        let env = Env::default();
         let expand = SizedBox::<()>::new(Label::new("hello!")).height(200.);
         let bc = BoxConstraints::tight(Size::new(400., 400.)).loosen();
-        let child_bc = expand.child_constraints(&bc);
+        let child_bc = expand.child_constraints(&bc, &env);
         assert_eq!(child_bc.min(), Size::new(0., 200.,));
         assert_eq!(child_bc.max(), Size::new(400., 200.,));
     }
+
+    #[test]
+    fn min_max() {
+        let env = Env::default();
+        let bounded = SizedBox::<()>::new(Label::new("hello!"))
+            .min_width(100.)
+            .max_width(200.)
+            .max_height(500.);
+        let bc = BoxConstraints::tight(Size::new(400., 400.)).loosen();
+        let child_bc = bounded.child_constraints(&bc, &env);
+        assert_eq!(child_bc.min(), Size::new(100., 0.));
+        assert_eq!(child_bc.max(), Size::new(200., 400.));
+
+        // the parent's constraints win
+        let bc = BoxConstraints::tight(Size::new(50., 50.));
+        let child_bc = bounded.child_constraints(&bc, &env);
+        assert_eq!(child_bc.min(), Size::new(50., 50.));
+        assert_eq!(child_bc.max(), Size::new(50., 50.));
+
+        let capped = SizedBox::<()>::empty().expand().max_width(300.);
+        let bc = BoxConstraints::tight(Size::new(400., 400.)).loosen();
+        assert_eq!(
+            capped.child_constraints(&bc, &env).min(),
+            Size::new(300., 400.)
+        );
+    }
+
+    #[test]
+    fn size_from_env() {
+        const WIDTH: Key<f64> = Key::new("druid-tests.sized-box-width");
+        let mut env = Env::default();
+        env.set(WIDTH, 120.);
+
+        let sized = SizedBox::<()>::empty().width(WIDTH).height(20.);
+        let bc = BoxConstraints::tight(Size::new(400., 400.)).loosen();
+        assert_eq!(
+            sized.child_constraints(&bc, &env).min(),
+            Size::new(120., 20.)
+        );
+
+        env.set(WIDTH, 60.);
+        assert_eq!(
+            sized.child_constraints(&bc, &env).min(),
+            Size::new(60., 20.)
+        );
+    }
+
+    #[test]
+    fn aspect_ratio() {
+        let env = Env::default();
+        let wide = SizedBox::<()>::empty().aspect_ratio(2.0);
+        let bc = BoxConstraints::tight(Size::new(400., 400.)).loosen();
+        assert_eq!(
+            wide.child_constraints(&bc, &env).max(),
+            Size::new(400., 200.)
+        );
+
+        let tall = SizedBox::<()>::empty().aspect_ratio(0.5).expand();
+        assert_eq!(
+            tall.child_constraints(&bc, &env).max(),
+            Size::new(200., 400.)
+        );
+
+        let fixed_width = SizedBox::<()>::empty().width(100.).aspect_ratio(2.0);
+        assert_eq!(
+            fixed_width.child_constraints(&bc, &env).max(),
+            Size::new(100., 50.)
+        );
+
+        let unbounded = BoxConstraints::new(Size::new(10., 10.), Size::new(INFINITY, INFINITY));
+        let square = SizedBox::<()>::empty().aspect_ratio(1.0);
+        assert_eq!(
+            square.child_constraints(&unbounded, &env).max(),
+            Size::new(10., 10.)
+        );
+    }
 }
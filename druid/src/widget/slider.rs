@@ -12,20 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! A slider widget.
+//! Slider widgets.
 
 use crate::kurbo::{Circle, Shape};
 use crate::widget::prelude::*;
 use crate::{theme, LinearGradient, Point, Rect, UnitPoint};
 
-const TRACK_THICKNESS: f64 = 4.0;
 const BORDER_WIDTH: f64 = 2.0;
 const KNOB_STROKE_WIDTH: f64 = 2.0;
+const DEFAULT_TRACK_THICKNESS: f64 = 4.0;
 
 /// A slider, allowing interactive update of a numeric value.
 ///
 /// This slider implements `Widget<f64>`, and works on values clamped
 /// in the range `min..max`.
+///
+/// The size of the knob is determined by [`theme::BASIC_WIDGET_HEIGHT`], and
+/// the thickness of the track by [`theme::SLIDER_TRACK_THICKNESS`].
+///
+/// [`theme::BASIC_WIDGET_HEIGHT`]: ../theme/constant.BASIC_WIDGET_HEIGHT.html
+/// [`theme::SLIDER_TRACK_THICKNESS`]: ../theme/constant.SLIDER_TRACK_THICKNESS.html
 #[derive(Debug, Clone, Default)]
 pub struct Slider {
     min: f64,
@@ -118,6 +124,9 @@ impl Widget<f64> for Slider {
     fn lifecycle(&mut self, _ctx: &mut LifeCycleCtx, _event: &LifeCycle, _data: &f64, _env: &Env) {}
 
     fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &f64, _data: &f64, _env: &Env) {
+        if ctx.env_key_changed(&theme::SLIDER_TRACK_THICKNESS) {
+            ctx.request_layout();
+        }
         ctx.request_paint();
     }
 
@@ -125,7 +134,8 @@ impl Widget<f64> for Slider {
         bc.debug_check("Slider");
         let height = env.get(theme::BASIC_WIDGET_HEIGHT);
         let width = env.get(theme::WIDE_WIDGET_WIDTH);
-        let baseline_offset = (height / 2.0) - TRACK_THICKNESS;
+        let track_thickness = track_thickness(env);
+        let baseline_offset = (height / 2.0) - track_thickness;
         ctx.set_baseline_offset(baseline_offset);
         bc.constrain((width, height))
     }
@@ -135,26 +145,7 @@ impl Widget<f64> for Slider {
         let rect = ctx.size().to_rect();
         let knob_size = env.get(theme::BASIC_WIDGET_HEIGHT);
 
-        //Paint the background
-        let background_width = rect.width() - knob_size;
-        let background_origin = Point::new(knob_size / 2., (knob_size - TRACK_THICKNESS) / 2.);
-        let background_size = Size::new(background_width, TRACK_THICKNESS);
-        let background_rect = Rect::from_origin_size(background_origin, background_size)
-            .inset(-BORDER_WIDTH / 2.)
-            .to_rounded_rect(2.);
-
-        let background_gradient = LinearGradient::new(
-            UnitPoint::TOP,
-            UnitPoint::BOTTOM,
-            (
-                env.get(theme::BACKGROUND_LIGHT),
-                env.get(theme::BACKGROUND_DARK),
-            ),
-        );
-
-        ctx.stroke(background_rect, &env.get(theme::BORDER_DARK), BORDER_WIDTH);
-
-        ctx.fill(background_rect, &background_gradient);
+        paint_track(ctx, env);
 
         //Get ready to paint the knob
         let is_active = ctx.is_active();
@@ -162,41 +153,332 @@ impl Widget<f64> for Slider {
 
         let knob_position = (rect.width() - knob_size) * clamped + knob_size / 2.;
         self.knob_pos = Point::new(knob_position, knob_size / 2.);
-        let knob_circle = Circle::new(self.knob_pos, (knob_size - KNOB_STROKE_WIDTH) / 2.);
+        paint_knob(ctx, env, self.knob_pos, is_hovered, is_active);
+    }
+}
 
-        let normal_knob_gradient = LinearGradient::new(
-            UnitPoint::TOP,
-            UnitPoint::BOTTOM,
-            (
-                env.get(theme::FOREGROUND_LIGHT),
-                env.get(theme::FOREGROUND_DARK),
-            ),
+/// A slider with two knobs, allowing interactive selection of a range.
+///
+/// This slider implements `Widget<(f64, f64)>`, where the first value is the
+/// start of the range and the second its end. Both are clamped to the range
+/// `min..max`, and the knobs can't be dragged past each other, so the start
+/// never ends up greater than the end.
+///
+/// The part of the track between the knobs is painted with
+/// [`theme::PRIMARY_LIGHT`] and [`theme::PRIMARY_DARK`]; otherwise it looks
+/// like a [`Slider`].
+///
+/// ```
+/// use druid::widget::RangeSlider;
+/// use druid::{Data, Lens, WidgetExt};
+///
+/// #[derive(Clone, Data, Lens)]
+/// struct Filter {
+///     price: (f64, f64),
+/// }
+///
+/// let price_filter = RangeSlider::new()
+///     .with_range(0.0, 500.0)
+///     .lens(Filter::price);
+/// ```
+///
+/// [`theme::PRIMARY_LIGHT`]: ../theme/constant.PRIMARY_LIGHT.html
+/// [`theme::PRIMARY_DARK`]: ../theme/constant.PRIMARY_DARK.html
+/// [`Slider`]: struct.Slider.html
+#[derive(Debug, Clone, Default)]
+pub struct RangeSlider {
+    min: f64,
+    max: f64,
+    dragged_knob: Option<RangeKnob>,
+    hovered_knob: Option<RangeKnob>,
+    x_offset: f64,
+}
+
+/// One of the two knobs of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RangeKnob {
+    Start,
+    End,
+}
+
+impl RangeSlider {
+    /// Create a new `RangeSlider`.
+    pub fn new() -> RangeSlider {
+        RangeSlider {
+            min: 0.,
+            max: 1.,
+            dragged_knob: None,
+            hovered_knob: None,
+            x_offset: 0.,
+        }
+    }
+
+    /// Builder-style method to set the range covered by this slider.
+    ///
+    /// The default range is `0.0..1.0`.
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+}
+
+impl RangeSlider {
+    fn normalize(&self, value: f64) -> f64 {
+        (value.max(self.min).min(self.max) - self.min) / (self.max - self.min)
+    }
+
+    fn knob_pos(&self, value: f64, knob_width: f64, slider_width: f64) -> Point {
+        let x = (slider_width - knob_width) * self.normalize(value) + knob_width / 2.;
+        Point::new(x, knob_width / 2.)
+    }
+
+    fn calculate_value(&self, mouse_x: f64, knob_width: f64, slider_width: f64) -> f64 {
+        let scalar = ((mouse_x + self.x_offset - knob_width / 2.) / (slider_width - knob_width))
+            .max(0.0)
+            .min(1.0);
+        self.min + scalar * (self.max - self.min)
+    }
+
+    /// The knob closest to `mouse_pos`, and whether it was actually hit.
+    fn closest_knob(
+        &self,
+        data: (f64, f64),
+        knob_width: f64,
+        slider_width: f64,
+        mouse_pos: Point,
+    ) -> (RangeKnob, Point, bool) {
+        let start = self.knob_pos(data.0, knob_width, slider_width);
+        let end = self.knob_pos(data.1, knob_width, slider_width);
+        let to_start = (mouse_pos.x - start.x).abs();
+        let to_end = (mouse_pos.x - end.x).abs();
+        // if the knobs overlap, pick the one that can move towards the mouse
+        let (knob, pos) = if to_start < to_end || (to_start == to_end && mouse_pos.x < start.x) {
+            (RangeKnob::Start, start)
+        } else {
+            (RangeKnob::End, end)
+        };
+        let hit = Circle::new(pos, knob_width / 2.).winding(mouse_pos) > 0;
+        (knob, pos, hit)
+    }
+
+    /// Move the dragged knob to `mouse_x`, without crossing the other one.
+    ///
+    /// If we grabbed both knobs at once, the direction of the drag decides
+    /// which one moves.
+    fn drag_to(&mut self, data: &mut (f64, f64), mouse_x: f64, knob_width: f64, slider_width: f64) {
+        let value = self.calculate_value(mouse_x, knob_width, slider_width);
+        if self.dragged_knob.is_none() {
+            if value < data.0 {
+                self.dragged_knob = Some(RangeKnob::Start);
+            } else if value > data.1 {
+                self.dragged_knob = Some(RangeKnob::End);
+            }
+        }
+        match self.dragged_knob {
+            Some(RangeKnob::Start) => data.0 = value.min(data.1),
+            Some(RangeKnob::End) => data.1 = value.max(data.0),
+            None => (),
+        }
+    }
+}
+
+impl Widget<(f64, f64)> for RangeSlider {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut (f64, f64), env: &Env) {
+        let knob_size = env.get(theme::BASIC_WIDGET_HEIGHT);
+        let slider_width = ctx.size().width;
+
+        match event {
+            Event::MouseDown(mouse) => {
+                ctx.set_active(true);
+                let (knob, knob_pos, hit) =
+                    self.closest_knob(*data, knob_size, slider_width, mouse.pos);
+                if hit {
+                    let overlapping = data.0 >= data.1;
+                    self.dragged_knob = if overlapping { None } else { Some(knob) };
+                    self.x_offset = knob_pos.x - mouse.pos.x;
+                } else {
+                    self.dragged_knob = Some(knob);
+                    self.x_offset = 0.;
+                    self.drag_to(data, mouse.pos.x, knob_size, slider_width);
+                }
+                ctx.request_paint();
+            }
+            Event::MouseUp(mouse) => {
+                if ctx.is_active() {
+                    ctx.set_active(false);
+                    self.drag_to(data, mouse.pos.x, knob_size, slider_width);
+                    self.dragged_knob = None;
+                    ctx.request_paint();
+                }
+            }
+            Event::MouseMove(mouse) => {
+                if ctx.is_active() {
+                    self.drag_to(data, mouse.pos.x, knob_size, slider_width);
+                    ctx.request_paint();
+                }
+                if ctx.is_hot() {
+                    let (knob, _, hit) =
+                        self.closest_knob(*data, knob_size, slider_width, mouse.pos);
+                    let hovered = if hit { Some(knob) } else { None };
+                    if hovered != self.hovered_knob {
+                        self.hovered_knob = hovered;
+                        ctx.request_paint();
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn lifecycle(
+        &mut self,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        _data: &(f64, f64),
+        _env: &Env,
+    ) {
+        if let LifeCycle::HotChanged(false) = event {
+            if self.hovered_knob.take().is_some() {
+                ctx.request_paint();
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        ctx: &mut UpdateCtx,
+        _old_data: &(f64, f64),
+        _data: &(f64, f64),
+        _env: &Env,
+    ) {
+        if ctx.env_key_changed(&theme::SLIDER_TRACK_THICKNESS) {
+            ctx.request_layout();
+        }
+        ctx.request_paint();
+    }
+
+    fn layout(
+        &mut self,
+        ctx: &mut LayoutCtx,
+        bc: &BoxConstraints,
+        _data: &(f64, f64),
+        env: &Env,
+    ) -> Size {
+        bc.debug_check("RangeSlider");
+        let height = env.get(theme::BASIC_WIDGET_HEIGHT);
+        let width = env.get(theme::WIDE_WIDGET_WIDTH);
+        let track_thickness = track_thickness(env);
+        let baseline_offset = (height / 2.0) - track_thickness;
+        ctx.set_baseline_offset(baseline_offset);
+        bc.constrain((width, height))
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &(f64, f64), env: &Env) {
+        let knob_size = env.get(theme::BASIC_WIDGET_HEIGHT);
+        let track_thickness = track_thickness(env);
+        let slider_width = ctx.size().width;
+
+        paint_track(ctx, env);
+
+        let start = self.knob_pos(data.0, knob_size, slider_width);
+        let end = self.knob_pos(data.1, knob_size, slider_width);
+
+        //Paint the selected part of the track
+        let selected_rect = Rect::new(
+            start.x,
+            (knob_size - track_thickness) / 2.,
+            end.x,
+            (knob_size + track_thickness) / 2.,
         );
-        let flipped_knob_gradient = LinearGradient::new(
+        let selected_gradient = LinearGradient::new(
             UnitPoint::TOP,
             UnitPoint::BOTTOM,
-            (
-                env.get(theme::FOREGROUND_DARK),
-                env.get(theme::FOREGROUND_LIGHT),
-            ),
+            (env.get(theme::PRIMARY_LIGHT), env.get(theme::PRIMARY_DARK)),
         );
+        ctx.fill(selected_rect, &selected_gradient);
 
-        let knob_gradient = if is_active {
-            flipped_knob_gradient
-        } else {
-            normal_knob_gradient
-        };
+        for (knob, pos) in [(RangeKnob::Start, start), (RangeKnob::End, end)].iter() {
+            let is_active = ctx.is_active() && self.dragged_knob == Some(*knob);
+            let is_hovered = self.hovered_knob == Some(*knob);
+            paint_knob(ctx, env, *pos, is_hovered, is_active);
+        }
+    }
+}
 
-        //Paint the border
-        let border_color = if is_hovered || is_active {
-            env.get(theme::FOREGROUND_LIGHT)
-        } else {
-            env.get(theme::FOREGROUND_DARK)
-        };
+/// The thickness of the track, falling back to the default for themes
+/// that don't set [`theme::SLIDER_TRACK_THICKNESS`].
+///
+/// [`theme::SLIDER_TRACK_THICKNESS`]: ../theme/constant.SLIDER_TRACK_THICKNESS.html
+fn track_thickness(env: &Env) -> f64 {
+    env.try_get(theme::SLIDER_TRACK_THICKNESS)
+        .unwrap_or(DEFAULT_TRACK_THICKNESS)
+}
 
-        ctx.stroke(knob_circle, &border_color, KNOB_STROKE_WIDTH);
+/// Paint the background track shared by [`Slider`] and [`RangeSlider`].
+fn paint_track(ctx: &mut PaintCtx, env: &Env) {
+    let rect = ctx.size().to_rect();
+    let knob_size = env.get(theme::BASIC_WIDGET_HEIGHT);
+    let track_thickness = track_thickness(env);
 
-        //Actually paint the knob
-        ctx.fill(knob_circle, &knob_gradient);
-    }
+    let background_width = rect.width() - knob_size;
+    let background_origin = Point::new(knob_size / 2., (knob_size - track_thickness) / 2.);
+    let background_size = Size::new(background_width, track_thickness);
+    let background_rect = Rect::from_origin_size(background_origin, background_size)
+        .inset(-BORDER_WIDTH / 2.)
+        .to_rounded_rect(2.);
+
+    let background_gradient = LinearGradient::new(
+        UnitPoint::TOP,
+        UnitPoint::BOTTOM,
+        (
+            env.get(theme::BACKGROUND_LIGHT),
+            env.get(theme::BACKGROUND_DARK),
+        ),
+    );
+
+    ctx.stroke(background_rect, &env.get(theme::BORDER_DARK), BORDER_WIDTH);
+
+    ctx.fill(background_rect, &background_gradient);
+}
+
+/// Paint a knob centered on `pos`.
+fn paint_knob(ctx: &mut PaintCtx, env: &Env, pos: Point, is_hovered: bool, is_active: bool) {
+    let knob_size = env.get(theme::BASIC_WIDGET_HEIGHT);
+    let knob_circle = Circle::new(pos, (knob_size - KNOB_STROKE_WIDTH) / 2.);
+
+    let normal_knob_gradient = LinearGradient::new(
+        UnitPoint::TOP,
+        UnitPoint::BOTTOM,
+        (
+            env.get(theme::FOREGROUND_LIGHT),
+            env.get(theme::FOREGROUND_DARK),
+        ),
+    );
+    let flipped_knob_gradient = LinearGradient::new(
+        UnitPoint::TOP,
+        UnitPoint::BOTTOM,
+        (
+            env.get(theme::FOREGROUND_DARK),
+            env.get(theme::FOREGROUND_LIGHT),
+        ),
+    );
+
+    let knob_gradient = if is_active {
+        flipped_knob_gradient
+    } else {
+        normal_knob_gradient
+    };
+
+    //Paint the border
+    let border_color = if is_hovered || is_active {
+        env.get(theme::FOREGROUND_LIGHT)
+    } else {
+        env.get(theme::FOREGROUND_DARK)
+    };
+
+    ctx.stroke(knob_circle, &border_color, KNOB_STROKE_WIDTH);
+
+    //Actually paint the knob
+    ctx.fill(knob_circle, &knob_gradient);
 }
@@ -0,0 +1,131 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An empty widget that takes up space.
+
+use crate::widget::prelude::*;
+use crate::widget::Axis;
+use crate::{Data, KeyOrValue};
+
+/// An empty widget that only takes up space.
+///
+/// A `Spacer` draws nothing and ignores all events. It works with any data,
+/// so it can be dropped into any widget tree.
+///
+/// A spacer is either [`fixed`], taking up a certain length, or [`flex`],
+/// taking up as much space as its parent allows. By default it does this on
+/// both axes; use [`axis`] to only take up space along one of them, so that
+/// for instance a spacer in a row does not make the row taller.
+///
+/// [`Flex`] has [`with_spacer`] and [`with_flex_spacer`] methods that add
+/// a spacer along the right axis for you.
+///
+/// # Examples
+///
+/// ```
+/// use druid::widget::{Axis, Button, Flex, Spacer};
+///
+/// let buttons: Flex<()> = Flex::row()
+///     .with_child(Button::new("Back"))
+///     .with_flex_child(Spacer::flex().axis(Axis::Horizontal), 1.0)
+///     .with_child(Button::new("Next"));
+/// ```
+///
+/// [`fixed`]: #method.fixed
+/// [`flex`]: #method.flex
+/// [`axis`]: #method.axis
+/// [`Flex`]: struct.Flex.html
+/// [`with_spacer`]: struct.Flex.html#method.with_spacer
+/// [`with_flex_spacer`]: struct.Flex.html#method.with_flex_spacer
+pub struct Spacer {
+    len: Option<KeyOrValue<f64>>,
+    axis: Option<Axis>,
+}
+
+impl Spacer {
+    /// Create a spacer with a fixed length.
+    ///
+    /// The length can be a value or a [`Key`] into the [`Env`], such as
+    /// [`theme::WIDGET_PADDING_HORIZONTAL`].
+    ///
+    /// [`Key`]: ../struct.Key.html
+    /// [`Env`]: ../struct.Env.html
+    /// [`theme::WIDGET_PADDING_HORIZONTAL`]: ../theme/constant.WIDGET_PADDING_HORIZONTAL.html
+    pub fn fixed(len: impl Into<KeyOrValue<f64>>) -> Self {
+        Spacer {
+            len: Some(len.into()),
+            axis: None,
+        }
+    }
+
+    /// Create a spacer that is as large as its constraints allow.
+    ///
+    /// In a [`Flex`], it should be added as a flex child, so it gets a share
+    /// of the remaining space.
+    ///
+    /// [`Flex`]: struct.Flex.html
+    pub fn flex() -> Self {
+        Spacer {
+            len: None,
+            axis: None,
+        }
+    }
+
+    /// Builder-style method to only take up space along `axis`.
+    ///
+    /// On the other axis, the spacer is as small as its constraints allow.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+}
+
+impl<T: Data> Widget<T> for Spacer {
+    fn event(&mut self, _: &mut EventCtx, _: &Event, _: &mut T, _: &Env) {}
+    fn lifecycle(&mut self, _: &mut LifeCycleCtx, _: &LifeCycle, _: &T, _: &Env) {}
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _: &T, _: &T, _: &Env) {
+        if let Some(len) = &self.len {
+            if ctx.env_key_changed(len) {
+                ctx.request_layout();
+            }
+        }
+    }
+
+    fn layout(&mut self, _: &mut LayoutCtx, bc: &BoxConstraints, _: &T, env: &Env) -> Size {
+        bc.debug_check("Spacer");
+
+        let size = match &self.len {
+            Some(len) => {
+                let len = len.resolve(env);
+                Size::new(len, len)
+            }
+            None => {
+                // we can't expand to infinity, so we stay small instead
+                let finite_or_zero = |value: f64| if value.is_finite() { value } else { 0.0 };
+                Size::new(
+                    finite_or_zero(bc.max().width),
+                    finite_or_zero(bc.max().height),
+                )
+            }
+        };
+        let size = match self.axis {
+            Some(axis) => axis.pack(axis.major(size), 0.0).into(),
+            None => size,
+        };
+        bc.constrain(size)
+    }
+
+    fn paint(&mut self, _: &mut PaintCtx, _: &T, _: &Env) {}
+}
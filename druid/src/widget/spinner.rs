@@ -25,7 +25,13 @@ use druid::{theme, Color, Data, KeyOrValue, Point, Vec2};
 /// To customize the spinner's size, you can place it inside a [`SizedBox`]
 /// that has a fixed width and height.
 ///
+/// The spinner starts animating as soon as it is added to the widget tree,
+/// and requests a new animation frame for as long as it is part of it.
+/// It doesn't depend on the data, so it can be used with any `T: Data`.
+/// While [`theme::REDUCED_MOTION`] is set, it stands still.
+///
 /// [`SizedBox`]: struct.SizedBox.html
+/// [`theme::REDUCED_MOTION`]: ../theme/constant.REDUCED_MOTION.html
 pub struct Spinner {
     t: f64,
     color: KeyOrValue<Color>,
@@ -67,8 +73,11 @@ impl Default for Spinner {
 }
 
 impl<T: Data> Widget<T> for Spinner {
-    fn event(&mut self, ctx: &mut EventCtx, event: &Event, _data: &mut T, _env: &Env) {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, _data: &mut T, env: &Env) {
         if let Event::AnimFrame(interval) = event {
+            if env.get(theme::REDUCED_MOTION) {
+                return;
+            }
             self.t += (*interval as f64) * 1e-9;
             if self.t >= 1.0 {
                 self.t = 0.0;
@@ -78,14 +87,24 @@ impl<T: Data> Widget<T> for Spinner {
         }
     }
 
-    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &T, _env: &Env) {
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, _data: &T, env: &Env) {
         if let LifeCycle::WidgetAdded = event {
-            ctx.request_anim_frame();
+            if !env.get(theme::REDUCED_MOTION) {
+                ctx.request_anim_frame();
+            }
             ctx.request_paint();
         }
     }
 
-    fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &T, _data: &T, _env: &Env) {}
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, _data: &T, env: &Env) {
+        if ctx.env_key_changed(&theme::REDUCED_MOTION) && !env.get(theme::REDUCED_MOTION) {
+            // start moving again
+            ctx.request_anim_frame();
+        }
+        if ctx.env_key_changed(&self.color) {
+            ctx.request_paint();
+        }
+    }
 
     fn layout(
         &mut self,
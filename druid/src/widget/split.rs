@@ -84,6 +84,11 @@ impl<T> Split<T> {
 
     /// Builder-style method to set the minimum size for both sides of the split axis.
     ///
+    /// Neither child will be made smaller than its minimum size along the
+    /// split axis, whether by dragging the splitter bar or by the container
+    /// shrinking. If there is not enough room for both minimums, the shortfall
+    /// is split evenly between the two sides.
+    ///
     /// The value must be greater than or equal to `0.0`.
     /// The value will be rounded up to the nearest integer.
     pub fn min_size(mut self, first: f64, second: f64) -> Self {
@@ -148,24 +153,32 @@ impl<T> Split<T> {
         (self.bar_area() - self.bar_size) / 2.0
     }
 
+    /// Returns the size of the split axis that is left for the children,
+    /// given the specified total size.
+    fn reduced_axis_size(&self, size: Size) -> f64 {
+        (self.split_axis.major(size) - self.bar_area()).max(0.0)
+    }
+
+    /// Returns the size of the first child along the split axis,
+    /// given the specified total size.
+    ///
+    /// This respects the minimum sizes of both children.
+    fn split_position(&self, size: Size) -> f64 {
+        let reduced_axis_size = self.reduced_axis_size(size);
+        let (min_limit, max_limit) = self.split_side_limits(reduced_axis_size);
+        clamp(
+            (reduced_axis_size * self.split_point_effective).floor(),
+            min_limit,
+            max_limit,
+        )
+    }
+
     /// Returns the location of the edges of the splitter bar area,
     /// given the specified total size.
     fn bar_edges(&self, size: Size) -> (f64, f64) {
-        let bar_area = self.bar_area();
-        match self.split_axis {
-            Axis::Horizontal => {
-                let reduced_width = size.width - bar_area;
-                let edge1 = (reduced_width * self.split_point_effective).floor();
-                let edge2 = edge1 + bar_area;
-                (edge1, edge2)
-            }
-            Axis::Vertical => {
-                let reduced_height = size.height - bar_area;
-                let edge1 = (reduced_height * self.split_point_effective).floor();
-                let edge2 = edge1 + bar_area;
-                (edge1, edge2)
-            }
-        }
+        let edge1 = self.split_position(size);
+        let edge2 = edge1 + self.bar_area();
+        (edge1, edge2)
     }
 
     /// Returns true if the provided mouse position is inside the splitter bar area.
@@ -177,10 +190,9 @@ impl<T> Split<T> {
         }
     }
 
-    /// Returns the minimum and maximum split coordinate of the provided size.
-    fn split_side_limits(&self, size: Size) -> (f64, f64) {
-        let split_axis_size = self.split_axis.major(size);
-
+    /// Returns the minimum and maximum split coordinate of the provided size
+    /// along the split axis.
+    fn split_side_limits(&self, split_axis_size: f64) -> (f64, f64) {
         let (mut min_limit, min_second) = self.min_size;
         let mut max_limit = (split_axis_size - min_second).max(0.0);
 
@@ -192,13 +204,15 @@ impl<T> Split<T> {
         (min_limit, max_limit)
     }
 
-    /// Set a new chosen split point.
+    /// Set a new chosen split point, centering the splitter bar on the mouse.
     fn update_split_point(&mut self, size: Size, mouse_pos: Point) {
-        let (min_limit, max_limit) = self.split_side_limits(size);
-        self.split_point_chosen = match self.split_axis {
-            Axis::Horizontal => clamp(mouse_pos.x, min_limit, max_limit) / size.width,
-            Axis::Vertical => clamp(mouse_pos.y, min_limit, max_limit) / size.height,
+        let reduced_axis_size = self.reduced_axis_size(size);
+        if reduced_axis_size <= std::f64::EPSILON {
+            return;
         }
+        let (min_limit, max_limit) = self.split_side_limits(reduced_axis_size);
+        let pos = self.split_axis.major_pos(mouse_pos) - self.bar_area() / 2.0;
+        self.split_point_chosen = clamp(pos, min_limit, max_limit) / reduced_axis_size;
     }
 
     /// Returns the color of the splitter bar.
@@ -290,7 +304,7 @@ impl<T: Data> Widget<T> for Split<T> {
                     if mouse.button.is_left() && ctx.is_active() {
                         ctx.set_active(false);
                         self.update_split_point(ctx.size(), mouse.pos);
-                        ctx.request_paint();
+                        ctx.request_layout();
                     }
                 }
                 Event::MouseMove(mouse) => {
@@ -356,8 +370,8 @@ impl<T: Data> Widget<T> for Split<T> {
 
         // Update our effective split point to respect our constraints
         self.split_point_effective = {
-            let (min_limit, max_limit) = self.split_side_limits(reduced_size);
             let reduced_axis_size = self.split_axis.major(reduced_size);
+            let (min_limit, max_limit) = self.split_side_limits(reduced_axis_size);
             if reduced_axis_size.is_infinite() || reduced_axis_size <= std::f64::EPSILON {
                 0.5
             } else {
@@ -369,11 +383,10 @@ impl<T: Data> Widget<T> for Split<T> {
             }
         };
 
+        let child1_major = self.split_position(my_size);
         let (child1_bc, child2_bc) = match self.split_axis {
             Axis::Horizontal => {
-                let child1_width = (reduced_size.width * self.split_point_effective)
-                    .floor()
-                    .max(0.0);
+                let child1_width = child1_major;
                 let child2_width = (reduced_size.width - child1_width).max(0.0);
                 (
                     BoxConstraints::new(
@@ -387,9 +400,7 @@ impl<T: Data> Widget<T> for Split<T> {
                 )
             }
             Axis::Vertical => {
-                let child1_height = (reduced_size.height * self.split_point_effective)
-                    .floor()
-                    .max(0.0);
+                let child1_height = child1_major;
                 let child2_height = (reduced_size.height - child1_height).max(0.0);
                 (
                     BoxConstraints::new(
@@ -0,0 +1,162 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that updates the environment for its child based on whether it
+//! is hot or active.
+
+use crate::widget::prelude::*;
+use crate::{Point, WidgetPod};
+
+/// Whether a [`StateScope`] is hovered or pressed.
+///
+/// [`StateScope`]: struct.StateScope.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InteractionState {
+    /// `true` while the mouse is over the widget.
+    ///
+    /// See [`EventCtx::is_hot`](../struct.EventCtx.html#method.is_hot).
+    pub is_hot: bool,
+    /// `true` while the widget, or one of its descendants, is active; this
+    /// usually means that the mouse was pressed on it.
+    ///
+    /// See [`EventCtx::is_active`](../struct.EventCtx.html#method.is_active).
+    pub is_active: bool,
+}
+
+/// A widget that accepts a closure to update the environment for its child,
+/// depending on whether it is hovered or pressed.
+///
+/// This is like an [`EnvScope`], except that the closure is also given the
+/// current [`InteractionState`]. Whenever that changes, the child is updated
+/// with the new environment and repainted, so it is possible to build hover
+/// and press styling out of regular widgets:
+///
+/// ```
+/// use druid::widget::{Container, Label};
+/// use druid::{theme, Widget, WidgetExt};
+///
+/// fn my_button() -> impl Widget<u32> {
+///     Container::new(Label::new("Click me"))
+///         .background(theme::BUTTON_DARK)
+///         .border(theme::BORDER_DARK, 1.0)
+///         .state_scope(|env, _data, state| {
+///             if state.is_active {
+///                 let pressed = env.get(theme::PRIMARY_DARK);
+///                 env.set(theme::BUTTON_DARK, pressed);
+///             } else if state.is_hot {
+///                 let hovered = env.get(theme::BORDER_LIGHT);
+///                 env.set(theme::BORDER_DARK, hovered);
+///             }
+///         })
+///         .on_click(|_ctx, data, _env| *data += 1)
+/// }
+/// ```
+///
+/// The state is that of the `StateScope` itself, so a controller like
+/// [`Click`] that sets the widget active can go on either side of it.
+///
+/// This is available as [`WidgetExt::state_scope`] for convenience.
+///
+/// [`EnvScope`]: struct.EnvScope.html
+/// [`InteractionState`]: struct.InteractionState.html
+/// [`Click`]: struct.Click.html
+/// [`WidgetExt::state_scope`]: ../trait.WidgetExt.html#method.state_scope
+pub struct StateScope<T, W> {
+    f: Box<dyn Fn(&mut Env, &T, InteractionState)>,
+    child: WidgetPod<T, W>,
+    state: InteractionState,
+}
+
+impl<T, W: Widget<T>> StateScope<T, W> {
+    /// Create a widget that updates the environment for its descendants
+    /// with `f`, based on the data and the current [`InteractionState`].
+    ///
+    /// [`InteractionState`]: struct.InteractionState.html
+    pub fn new(f: impl Fn(&mut Env, &T, InteractionState) + 'static, child: W) -> Self {
+        StateScope {
+            f: Box::new(f),
+            child: WidgetPod::new(child),
+            state: InteractionState::default(),
+        }
+    }
+
+    fn child_env(&self, env: &Env, data: &T) -> Env {
+        let mut new_env = env.clone();
+        (self.f)(&mut new_env, data, self.state);
+        new_env
+    }
+
+    /// Store the new state, returning `true` if it changed.
+    fn set_state(&mut self, is_hot: bool, is_active: bool) -> bool {
+        let state = InteractionState {
+            is_hot,
+            is_active: is_active || self.child.has_active(),
+        };
+        let changed = state != self.state;
+        self.state = state;
+        changed
+    }
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for StateScope<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let new_env = self.child_env(env, data);
+        self.child.event(ctx, event, data, &new_env);
+
+        if self.set_state(ctx.is_hot(), ctx.is_active()) {
+            ctx.request_update();
+            ctx.request_paint();
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        let new_env = self.child_env(env, data);
+        self.child.lifecycle(ctx, event, data, &new_env);
+
+        if self.set_state(ctx.is_hot(), ctx.is_active()) {
+            ctx.request_update();
+            ctx.request_paint();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        let new_env = self.child_env(env, data);
+
+        // as in `EnvScope`, repaint if the new data gives a different env
+        if !old_data.same(data) {
+            let mut old_env = env.clone();
+            (self.f)(&mut old_env, old_data, self.state);
+            if !old_env.same(&new_env) {
+                ctx.request_paint();
+            }
+        }
+
+        self.child.update(ctx, data, &new_env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("StateScope");
+
+        let new_env = self.child_env(env, data);
+        let size = self.child.layout(ctx, bc, data, &new_env);
+        self.child.set_origin(ctx, data, &new_env, Point::ORIGIN);
+        ctx.set_paint_insets(self.child.paint_insets());
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let new_env = self.child_env(env, data);
+        self.child.paint(ctx, data, &new_env);
+    }
+}
@@ -28,6 +28,12 @@ const STEPPER_REPEAT_DELAY: Duration = Duration::from_millis(500);
 const STEPPER_REPEAT: Duration = Duration::from_millis(200);
 
 /// A stepper widget for step-wise increasing and decreasing a value.
+///
+/// Clicking the top half increases the value by one step, and clicking the
+/// bottom half decreases it; holding the mouse button down keeps repeating
+/// the change. The value is always kept within the [range].
+///
+/// [range]: #method.with_range
 pub struct Stepper {
     max: f64,
     min: f64,
@@ -190,8 +196,9 @@ impl Widget<f64> for Stepper {
         ))
     }
 
-    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut f64, env: &Env) {
-        let height = env.get(theme::BORDERED_WIDGET_HEIGHT);
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut f64, _env: &Env) {
+        // we may have been given more height than we asked for
+        let height = ctx.size().height;
 
         match event {
             Event::MouseDown(mouse) => {
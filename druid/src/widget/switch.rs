@@ -21,11 +21,16 @@ use crate::piet::{LinearGradient, RenderContext, UnitPoint};
 use crate::widget::prelude::*;
 use crate::{theme, ArcStr, Point, TextLayout};
 
-const SWITCH_CHANGE_TIME: f64 = 0.2;
 const SWITCH_PADDING: f64 = 3.;
 const SWITCH_WIDTH_RATIO: f64 = 2.75;
 
 /// A switch that toggles a `bool`.
+///
+/// Clicking the switch, or dragging its knob past the middle, toggles the
+/// value. The knob then slides to the new position over
+/// [`theme::SWITCH_TOGGLE_DURATION`].
+///
+/// [`theme::SWITCH_TOGGLE_DURATION`]: ../theme/constant.SWITCH_TOGGLE_DURATION.html
 #[derive(Debug, Clone)]
 pub struct Switch {
     knob_pos: Point,
@@ -111,8 +116,14 @@ impl Widget<bool> for Switch {
                 ctx.set_active(false);
 
                 self.knob_dragged = false;
-                self.animation_in_progress = true;
-                ctx.request_anim_frame();
+                if env.get(theme::REDUCED_MOTION) {
+                    // snap straight to the new position
+                    self.animation_in_progress = false;
+                    ctx.request_paint();
+                } else {
+                    self.animation_in_progress = true;
+                    ctx.request_anim_frame();
+                }
             }
             Event::MouseMove(mouse) => {
                 if ctx.is_active() {
@@ -134,10 +145,11 @@ impl Widget<bool> for Switch {
 
                 // move knob to right position depending on the value
                 if self.animation_in_progress {
+                    let duration = Duration::from_millis(env.get(theme::SWITCH_TOGGLE_DURATION));
                     let change_time = if *data {
-                        SWITCH_CHANGE_TIME
+                        duration.as_secs_f64()
                     } else {
-                        -SWITCH_CHANGE_TIME
+                        -duration.as_secs_f64()
                     };
                     let change = (switch_width / change_time) * delta;
                     self.knob_pos.x = (self.knob_pos.x + change).min(on_pos).max(off_pos);
@@ -162,10 +174,15 @@ impl Widget<bool> for Switch {
         }
     }
 
-    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &bool, data: &bool, _env: &Env) {
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &bool, data: &bool, env: &Env) {
         if old_data != data {
-            self.animation_in_progress = true;
-            ctx.request_anim_frame();
+            if env.get(theme::REDUCED_MOTION) {
+                self.animation_in_progress = false;
+                ctx.request_paint();
+            } else {
+                self.animation_in_progress = true;
+                ctx.request_anim_frame();
+            }
         }
     }
 
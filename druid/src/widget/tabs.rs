@@ -24,7 +24,10 @@ use std::rc::Rc;
 use crate::kurbo::Line;
 use crate::widget::prelude::*;
 use crate::widget::{Axis, Flex, Label, LabelText, LensScopeTransfer, Scope, ScopePolicy};
-use crate::{theme, Affine, Data, Insets, Lens, Point, SingleUse, WidgetExt, WidgetPod};
+use crate::{
+    theme, Affine, Data, HotKey, Insets, KbKey, Lens, Point, SingleUse, SysMods, WidgetExt,
+    WidgetPod,
+};
 
 type TabsScope<TP> = Scope<TabsScopePolicy<TP>, Box<dyn Widget<TabsState<TP>>>>;
 type TabBodyPod<TP> = WidgetPod<<TP as TabsPolicy>::Input, <TP as TabsPolicy>::BodyWidget>;
@@ -308,6 +311,28 @@ impl<TP: TabsPolicy> Widget<TabsState<TP>> for TabBar<TP> {
                 if let Some(idx) = self.find_idx(e.pos) {
                     data.selected = idx;
                 }
+                ctx.request_focus();
+            }
+            Event::KeyDown(k_e) if ctx.is_focused() => {
+                let (prev, next) = match self.axis {
+                    Axis::Horizontal => (KbKey::ArrowLeft, KbKey::ArrowRight),
+                    Axis::Vertical => (KbKey::ArrowUp, KbKey::ArrowDown),
+                };
+                match k_e {
+                    k_e if HotKey::new(None, prev).matches(k_e) => {
+                        data.selected = data.selected.saturating_sub(1);
+                        ctx.set_handled();
+                    }
+                    k_e if HotKey::new(None, next).matches(k_e) => {
+                        if data.selected + 1 < self.tabs.len() {
+                            data.selected += 1;
+                        }
+                        ctx.set_handled();
+                    }
+                    k_e if HotKey::new(None, KbKey::Tab).matches(k_e) => ctx.focus_next(),
+                    k_e if HotKey::new(SysMods::Shift, KbKey::Tab).matches(k_e) => ctx.focus_prev(),
+                    _ => (),
+                }
             }
             Event::MouseMove(e) => {
                 let new_hot = if ctx.is_hot() {
@@ -335,10 +360,15 @@ impl<TP: TabsPolicy> Widget<TabsState<TP>> for TabBar<TP> {
         data: &TabsState<TP>,
         env: &Env,
     ) {
-        if let LifeCycle::WidgetAdded = event {
-            self.ensure_tabs(data);
-            ctx.children_changed();
-            ctx.request_layout();
+        match event {
+            LifeCycle::WidgetAdded => {
+                self.ensure_tabs(data);
+                ctx.children_changed();
+                ctx.request_layout();
+                ctx.register_for_focus();
+            }
+            LifeCycle::FocusChanged(_) => ctx.request_paint(),
+            _ => (),
         }
 
         for (_, tab) in self.tabs.iter_mut() {
@@ -403,6 +433,9 @@ impl<TP: TabsPolicy> Widget<TabsState<TP>> for TabBar<TP> {
             ctx.fill(rect, &bg);
 
             tab.paint(ctx, data, env);
+            if idx == data.selected && ctx.is_focused() {
+                ctx.stroke(rect.inset(-0.5), &highlight, 1.);
+            }
             if idx == data.selected {
                 let (maj_near, maj_far) = self.axis.major_span(rect);
                 let (min_near, min_far) = self.axis.minor_span(rect);
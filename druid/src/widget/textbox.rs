@@ -24,8 +24,8 @@ use crate::text::{
 };
 use crate::widget::prelude::*;
 use crate::{
-    theme, Affine, Color, Cursor, Data, FontDescriptor, HotKey, KbKey, KeyOrValue, Point, Selector,
-    SysMods, TextAlignment, TimerToken, Vec2,
+    theme, Affine, Color, Cursor, Data, FontDescriptor, HotKey, KbKey, KeyOrValue, Point, Rect,
+    Selector, SysMods, TextAlignment, TimerToken, Vec2,
 };
 
 const MAC_OR_LINUX: bool = cfg!(any(target_os = "macos", target_os = "linux"));
@@ -34,6 +34,8 @@ const CURSOR_BLINK_DURATION: Duration = Duration::from_millis(500);
 const BEGIN_EDITING: Selector = Selector::new("druid.builtin.textbox-begin-editing");
 const COMPLETE_EDITING: Selector = Selector::new("druid.builtin.textbox-complete-editing");
 const CANCEL_EDITING: Selector = Selector::new("druid.builtin.textbox-cancel-editing");
+const SCROLL_TO_SELF: Selector = Selector::new("druid.builtin.textbox-scroll-to-self");
+const SCROLL_TO_CURSOR: Selector = Selector::new("druid.builtin.textbox-scroll-to-cursor");
 
 /// A widget that allows user text input.
 ///
@@ -64,6 +66,9 @@ pub struct TextBox<T> {
     /// on the click position; if focus happens automatically (e.g. on tab)
     /// then we select our entire contents.
     was_focused_from_click: bool,
+    /// true if the cursor moved, and should be scrolled into view once the
+    /// layout is up to date.
+    scroll_to_cursor: bool,
 }
 
 /// A `TextBox` that uses a [`Formatter`] to handle formatting and validation
@@ -152,10 +157,31 @@ impl<T> TextBox<T> {
             alignment_offset: 0.0,
             text_pos: Point::ZERO,
             was_focused_from_click: false,
+            scroll_to_cursor: false,
         }
     }
 
     /// Create a new multi-line `TextBox`.
+    ///
+    /// The text wraps at the width of the text box, which grows vertically
+    /// to fit it. Enter inserts a newline, and the up and down arrow keys
+    /// move the cursor between lines, including wrapped ones.
+    ///
+    /// To limit its height, put it in a [`Scroll`]; the cursor is kept
+    /// in view while editing.
+    ///
+    /// ```
+    /// use druid::widget::TextBox;
+    /// use druid::WidgetExt;
+    ///
+    /// let notes = TextBox::<String>::multiline()
+    ///     .expand_width()
+    ///     .scroll()
+    ///     .vertical()
+    ///     .fix_height(200.);
+    /// ```
+    ///
+    /// [`Scroll`]: struct.Scroll.html
     pub fn multiline() -> Self {
         let mut this = TextBox::new();
         this.editor.set_multiline(true);
@@ -458,6 +484,14 @@ impl<T: TextStorage + EditableText> Widget<T> for TextBox<T> {
                 self.editor.cut(data);
                 ctx.set_handled();
             }
+            Event::Command(cmd) if cmd.is(SCROLL_TO_SELF) => {
+                ctx.scroll_to(ctx.size().to_rect());
+            }
+            Event::Command(cmd) if cmd.is(SCROLL_TO_CURSOR) => {
+                let line = self.editor.cursor_line();
+                let cursor = Rect::from_points(line.p0, line.p1) + self.text_pos.to_vec2();
+                ctx.scroll_to(cursor);
+            }
             Event::Command(cmd) if cmd.is(TextBox::PERFORM_EDIT) => {
                 let edit = cmd.get_unchecked(TextBox::PERFORM_EDIT);
                 self.editor.do_edit(edit.to_owned(), data);
@@ -472,9 +506,35 @@ impl<T: TextStorage + EditableText> Widget<T> for TextBox<T> {
                     // Tab and shift+tab
                     k_e if HotKey::new(None, KbKey::Tab).matches(k_e) => ctx.focus_next(),
                     k_e if HotKey::new(SysMods::Shift, KbKey::Tab).matches(k_e) => ctx.focus_prev(),
+                    // the clipboard shortcuts usually come through the menu
+                    // commands instead, but not every window has a menu.
+                    k_e if HotKey::new(SysMods::Cmd, "c").matches(k_e) => {
+                        if let Some(mut clipboard) = ctx.clipboard() {
+                            self.editor.copy_to(&mut clipboard, data);
+                        }
+                        ctx.set_handled();
+                    }
+                    k_e if HotKey::new(SysMods::Cmd, "x").matches(k_e) => {
+                        match ctx.clipboard() {
+                            Some(mut clipboard) => self.editor.cut_to(&mut clipboard, data),
+                            // without a running application there is no
+                            // clipboard to cut to, but the selection still goes.
+                            None => self.editor.do_edit(EditAction::Delete, data),
+                        }
+                        self.scroll_to_cursor = self.multiline;
+                        ctx.set_handled();
+                    }
+                    k_e if HotKey::new(SysMods::Cmd, "v").matches(k_e) => {
+                        if let Some(string) = ctx.clipboard().and_then(|c| c.get_string()) {
+                            self.editor.paste(string, data);
+                            self.scroll_to_cursor = self.multiline;
+                        }
+                        ctx.set_handled();
+                    }
                     k_e => {
                         if let Some(edit) = self.input_handler.handle_event(k_e) {
                             self.suppress_adjust_hscroll = matches!(edit, EditAction::SelectAll);
+                            self.scroll_to_cursor = self.multiline;
                             self.editor.do_edit(edit, data);
                             // an explicit request update in case the selection
                             // state has changed, but the data hasn't.
@@ -502,6 +562,10 @@ impl<T: TextStorage + EditableText> Widget<T> for TextBox<T> {
                     self.editor.select_all(data);
                 }
                 self.was_focused_from_click = false;
+                if *is_focused {
+                    // we can only ask to be scrolled into view from `event`
+                    ctx.submit_command(SCROLL_TO_SELF.to(ctx.widget_id()));
+                }
                 self.reset_cursor_blink(ctx.request_timer(CURSOR_BLINK_DURATION));
                 ctx.request_paint();
             }
@@ -514,9 +578,18 @@ impl<T: TextStorage + EditableText> Widget<T> for TextBox<T> {
         if !self.suppress_adjust_hscroll && !self.multiline {
             self.update_hscroll(ctx.size().width, env);
         }
+        if self.scroll_to_cursor {
+            // the editor's layout has caught up with the edit now, but we
+            // can only ask to be scrolled from `event`
+            self.scroll_to_cursor = false;
+            ctx.submit_command(SCROLL_TO_CURSOR.to(ctx.widget_id()));
+        }
         if ctx.env_changed() && self.placeholder.needs_rebuild_after_update(ctx) {
             ctx.request_layout();
         }
+        if ctx.env_key_changed(&theme::HIGH_CONTRAST) {
+            ctx.request_paint();
+        }
     }
 
     fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
@@ -555,7 +628,7 @@ impl<T: TextStorage + EditableText> Widget<T> for TextBox<T> {
         let background_color = env.get(theme::BACKGROUND_LIGHT);
         let selection_color = env.get(theme::SELECTION_COLOR);
         let cursor_color = env.get(theme::CURSOR_COLOR);
-        let border_width = env.get(theme::TEXTBOX_BORDER_WIDTH);
+        let border_width = theme::border_width(env, env.get(theme::TEXTBOX_BORDER_WIDTH));
         let text_insets = env.get(theme::TEXTBOX_INSETS);
 
         let is_focused = ctx.is_focused();
@@ -563,7 +636,7 @@ impl<T: TextStorage + EditableText> Widget<T> for TextBox<T> {
         let border_color = if is_focused {
             env.get(theme::PRIMARY_LIGHT)
         } else {
-            env.get(theme::BORDER_DARK)
+            theme::border_color(env, false)
         };
 
         // Paint the background
@@ -0,0 +1,160 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that shows a tooltip when hovered.
+
+use std::time::Duration;
+
+use crate::widget::prelude::*;
+use crate::widget::LabelText;
+use crate::{theme, ArcStr, Data, Point, Rect, TextLayout, TimerToken, Vec2};
+
+// how far the tooltip is drawn from the mouse cursor.
+const CURSOR_OFFSET: Vec2 = Vec2::new(0.0, 16.0);
+const TOOLTIP_PADDING: f64 = 4.0;
+
+/// A widget that shows a short piece of text when the mouse rests over its
+/// child.
+///
+/// The tooltip appears after the mouse has been still over the child for
+/// [`theme::TOOLTIP_DELAY`] milliseconds, and is drawn near the cursor, above
+/// any other widgets. It is hidden again when the mouse leaves the child or
+/// a mouse button is pressed.
+///
+/// This is available as [`WidgetExt::tooltip`] for convenience.
+///
+/// [`theme::TOOLTIP_DELAY`]: ../theme/constant.TOOLTIP_DELAY.html
+/// [`WidgetExt::tooltip`]: ../trait.WidgetExt.html#method.tooltip
+pub struct Tooltip<T, W> {
+    child: W,
+    text: LabelText<T>,
+    layout: TextLayout<ArcStr>,
+    timer: TimerToken,
+    mouse_pos: Point,
+    visible: bool,
+}
+
+impl<T: Data, W: Widget<T>> Tooltip<T, W> {
+    /// Create a new `Tooltip` showing `text` when `child` is hovered.
+    pub fn new(child: W, text: impl Into<LabelText<T>>) -> Self {
+        Tooltip {
+            child,
+            text: text.into(),
+            layout: TextLayout::new(),
+            timer: TimerToken::INVALID,
+            mouse_pos: Point::ORIGIN,
+            visible: false,
+        }
+    }
+
+    /// The rectangle covered by the tooltip, relative to our origin.
+    fn tooltip_rect(&self) -> Rect {
+        let size = self.layout.size();
+        let origin = self.mouse_pos + CURSOR_OFFSET;
+        Rect::from_origin_size(origin, size).inset(TOOLTIP_PADDING)
+    }
+
+    fn hide(&mut self, ctx: &mut EventCtx) {
+        self.timer = TimerToken::INVALID;
+        if self.visible {
+            self.visible = false;
+            ctx.request_paint_rect(self.tooltip_rect());
+        }
+    }
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for Tooltip<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        match event {
+            Event::MouseMove(mouse) if !self.visible => {
+                self.mouse_pos = mouse.pos;
+                let delay = Duration::from_millis(env.get(theme::TOOLTIP_DELAY));
+                self.timer = ctx.request_timer(delay);
+            }
+            Event::MouseDown(_) | Event::Wheel(_) => self.hide(ctx),
+            Event::Timer(token) if *token == self.timer => {
+                self.timer = TimerToken::INVALID;
+                if ctx.is_hot() {
+                    self.visible = true;
+                    ctx.request_paint();
+                    ctx.request_paint_rect(self.tooltip_rect());
+                }
+                return;
+            }
+            _ => (),
+        }
+        self.child.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        match event {
+            LifeCycle::WidgetAdded => {
+                self.text.resolve(data, env);
+                self.layout.set_text(self.text.display_text());
+            }
+            LifeCycle::HotChanged(false) => {
+                self.timer = TimerToken::INVALID;
+                if self.visible {
+                    self.visible = false;
+                    ctx.request_paint();
+                    ctx.request_paint_rect(self.tooltip_rect());
+                }
+            }
+            _ => (),
+        }
+        self.child.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, old_data: &T, data: &T, env: &Env) {
+        if self.text.resolve(data, env) {
+            self.layout.set_text(self.text.display_text());
+            ctx.request_layout();
+        }
+        if self.layout.needs_rebuild_after_update(ctx) {
+            ctx.request_layout();
+        }
+        self.child.update(ctx, old_data, data, env);
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("Tooltip");
+        self.layout.rebuild_if_needed(ctx.text(), env);
+        self.child.layout(ctx, bc, data, env)
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        self.child.paint(ctx, data, env);
+
+        if self.visible {
+            // we clone because we need to move it for paint_with_z_index
+            let text = self.layout.clone();
+            let rect = self.tooltip_rect();
+            let background = env.get(theme::BACKGROUND_LIGHT);
+            let border = env.get(theme::BORDER_LIGHT);
+            // the tooltip should be drawn above everything else
+            ctx.paint_with_z_index(u32::MAX, move |ctx| {
+                ctx.fill(rect, &background);
+                ctx.stroke(rect.inset(-0.5), &border, 1.0);
+                text.draw(
+                    ctx,
+                    rect.origin() + Vec2::new(TOOLTIP_PADDING, TOOLTIP_PADDING),
+                );
+            });
+        }
+    }
+
+    fn id(&self) -> Option<WidgetId> {
+        self.child.id()
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that applies an affine transform to its child.
+
+use crate::kurbo::{Affine, Point, Vec2};
+use crate::widget::prelude::*;
+use crate::{Data, WidgetPod};
+
+/// A widget that rotates, scales or otherwise transforms its child.
+///
+/// The transform is applied around the center of the widget, and only
+/// affects how the child is painted and where it receives mouse events; the
+/// child is laid out as if it wasn't transformed, and the `Transform` takes
+/// up the same space as its child. Mouse positions are mapped into the
+/// child's coordinate space, but like any widget, the `Transform` only
+/// receives mouse events inside its own layout rect.
+pub struct Transform<T, W> {
+    child: WidgetPod<T, W>,
+    transform: Affine,
+    // the transform our paint insets were computed for in the last layout.
+    layout_transform: Option<Affine>,
+}
+
+impl<T: Data, W: Widget<T>> Transform<T, W> {
+    /// Create a new widget that paints `child` with the given transform.
+    pub fn new(child: W, transform: Affine) -> Self {
+        Transform {
+            child: WidgetPod::new(child),
+            transform,
+            layout_transform: None,
+        }
+    }
+
+    /// Builder-style method to rotate the child by `angle` radians, in
+    /// addition to the current transform.
+    ///
+    /// Positive angles rotate clockwise.
+    pub fn rotate(mut self, angle: f64) -> Self {
+        self.transform = Affine::rotate(angle) * self.transform;
+        self
+    }
+
+    /// Builder-style method to scale the child, in addition to the current
+    /// transform.
+    pub fn scale(mut self, sx: f64, sy: f64) -> Self {
+        self.transform = Affine::scale_non_uniform(sx, sy) * self.transform;
+        self
+    }
+
+    /// Builder-style method to move the child by `offset`, in addition to the
+    /// current transform.
+    pub fn translate(mut self, offset: impl Into<Vec2>) -> Self {
+        self.transform = Affine::translate(offset) * self.transform;
+        self
+    }
+
+    /// Returns the current transform.
+    pub fn transform(&self) -> Affine {
+        self.transform
+    }
+
+    /// Set the transform.
+    ///
+    /// The caller is responsible for requesting layout, which moves the area
+    /// the widget paints to follow the new transform. If it doesn't, the
+    /// widget requests layout itself the next time it gets an event or an
+    /// update.
+    pub fn set_transform(&mut self, transform: Affine) {
+        self.transform = transform;
+    }
+
+    /// Returns a reference to the child widget.
+    pub fn child(&self) -> &W {
+        self.child.widget()
+    }
+
+    /// Returns a mutable reference to the child widget.
+    pub fn child_mut(&mut self) -> &mut W {
+        self.child.widget_mut()
+    }
+
+    /// Returns `true` if the transform changed since the last layout, which
+    /// means that our paint insets are out of date.
+    fn needs_layout(&self) -> bool {
+        self.layout_transform != Some(self.transform)
+    }
+
+    /// The transform from child coordinates to our coordinates.
+    fn child_transform(&self, size: Size) -> Affine {
+        let center = size.to_vec2() / 2.0;
+        Affine::translate(center) * self.transform * Affine::translate(-center)
+    }
+}
+
+impl<T: Data, W: Widget<T>> Widget<T> for Transform<T, W> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        let transform = self.child_transform(ctx.size());
+        let child_event = match event {
+            Event::MouseDown(_) | Event::MouseUp(_) | Event::MouseMove(_) | Event::Wheel(_)
+                if transform.determinant() == 0.0 =>
+            {
+                return;
+            }
+            Event::MouseDown(mouse) => {
+                let mut mouse = mouse.clone();
+                mouse.pos = transform.inverse() * mouse.pos;
+                Event::MouseDown(mouse)
+            }
+            Event::MouseUp(mouse) => {
+                let mut mouse = mouse.clone();
+                mouse.pos = transform.inverse() * mouse.pos;
+                Event::MouseUp(mouse)
+            }
+            Event::MouseMove(mouse) => {
+                let mut mouse = mouse.clone();
+                mouse.pos = transform.inverse() * mouse.pos;
+                Event::MouseMove(mouse)
+            }
+            Event::Wheel(mouse) => {
+                let mut mouse = mouse.clone();
+                mouse.pos = transform.inverse() * mouse.pos;
+                Event::Wheel(mouse)
+            }
+            _ => event.clone(),
+        };
+        self.child.event(ctx, &child_event, data, env);
+        if self.needs_layout() {
+            ctx.request_layout();
+        }
+        // the child's invalid rects are in its own coordinates, so we
+        // repaint everything we might cover instead.
+        if !ctx.widget_state.invalid.is_empty() {
+            ctx.request_paint();
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        self.child.lifecycle(ctx, event, data, env);
+        if self.needs_layout() {
+            ctx.request_layout();
+        }
+        if !ctx.widget_state.invalid.is_empty() {
+            ctx.request_paint();
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        self.child.update(ctx, data, env);
+        if self.needs_layout() {
+            ctx.request_layout();
+        }
+        if !ctx.widget_state.invalid.is_empty() {
+            ctx.request_paint();
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("Transform");
+        let size = self.child.layout(ctx, bc, data, env);
+        self.child.set_origin(ctx, data, env, Point::ORIGIN);
+        // we paint the bounding box of our transformed child, which can be
+        // well outside of our layout rect.
+        let painted = self
+            .child_transform(size)
+            .transform_rect_bbox(self.child.paint_rect());
+        ctx.set_paint_insets(painted - size.to_rect());
+        self.layout_transform = Some(self.transform);
+        size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        let transform = self.child_transform(ctx.size());
+        if transform.determinant() == 0.0 {
+            return;
+        }
+        let visible = transform
+            .inverse()
+            .transform_rect_bbox(ctx.region().bounding_box());
+        ctx.with_save(|ctx| {
+            ctx.transform(transform);
+            ctx.with_child_ctx(visible, |ctx| self.child.paint_raw(ctx, data, env));
+        });
+    }
+}
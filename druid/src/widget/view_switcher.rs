@@ -17,13 +17,40 @@
 use crate::widget::prelude::*;
 use crate::{Data, Point, WidgetPod};
 
-/// A widget that can switch dynamically between one of many views depending
-/// on application state.
-
 type ChildPicker<T, U> = dyn Fn(&T, &Env) -> U;
 type ChildBuilder<T, U> = dyn Fn(&U, &T, &Env) -> Box<dyn Widget<T>>;
 
-/// A widget that dynamically switches between two children.
+/// A widget that can switch dynamically between one of many views depending
+/// on application state.
+///
+/// The active child is only rebuilt when the value returned by the child
+/// picker changes; otherwise the existing child is kept, along with any
+/// internal state it may have.
+///
+/// # Examples
+///
+/// ```
+/// use druid::widget::{Label, ViewSwitcher};
+/// use druid::{Data, Widget, WidgetExt};
+///
+/// #[derive(Clone, Copy, Data, PartialEq)]
+/// enum Panel {
+///     Home,
+///     Settings,
+///     About,
+/// }
+///
+/// fn make_switcher() -> impl Widget<Panel> {
+///     ViewSwitcher::new(
+///         |panel: &Panel, _env| *panel,
+///         |panel, _data, _env| match panel {
+///             Panel::Home => Label::new("Home").boxed(),
+///             Panel::Settings => Label::new("Settings").boxed(),
+///             Panel::About => Label::new("About").boxed(),
+///         },
+///     )
+/// }
+/// ```
 pub struct ViewSwitcher<T, U> {
     child_picker: Box<ChildPicker<T, U>>,
     child_builder: Box<ChildBuilder<T, U>>,
@@ -90,6 +117,7 @@ impl<T: Data, U: Data> Widget<T> for ViewSwitcher<T, U> {
             Some(ref mut child) => {
                 let size = child.layout(ctx, bc, data, env);
                 child.set_origin(ctx, data, env, Point::ORIGIN);
+                ctx.set_paint_insets(child.paint_insets());
                 size
             }
             None => bc.max(),
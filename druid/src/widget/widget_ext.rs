@@ -14,12 +14,19 @@
 
 //! Convenience methods for widgets.
 
+use std::any::Any;
+use std::time::Duration;
+
 use super::invalidation::DebugInvalidation;
 use super::{
-    Align, BackgroundBrush, Click, Container, Controller, ControllerHost, EnvScope,
-    IdentityWrapper, LensWrap, Padding, Parse, SizedBox, WidgetId,
+    Align, BackgroundBrush, Click, Container, Controller, ControllerHost, Debounce, DisabledIf,
+    DragSource, DropTarget, EnvScope, FadeToBackground, FocusRing, IdentityWrapper,
+    InteractionState, LabelText, LensWrap, OnAdded, Padding, Parse, Scroll, Shortcut, SizedBox,
+    StateScope, Tooltip, WidgetId,
+};
+use crate::{
+    Color, Command, Data, Env, EventCtx, HotKey, Insets, KeyOrValue, Lens, UnitPoint, Widget,
 };
-use crate::{Color, Data, Env, EventCtx, Insets, KeyOrValue, Lens, UnitPoint, Widget};
 
 /// A trait that provides extra methods for combining `Widget`s.
 pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
@@ -31,6 +38,22 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
         Padding::new(insets, self)
     }
 
+    /// Wrap this widget in a [`Padding`] widget that only pads the left and
+    /// right sides.
+    ///
+    /// [`Padding`]: widget/struct.Padding.html
+    fn padding_horizontal(self, padding: f64) -> Padding<T> {
+        Padding::horizontal(padding, self)
+    }
+
+    /// Wrap this widget in a [`Padding`] widget that only pads the top and
+    /// bottom sides.
+    ///
+    /// [`Padding`]: widget/struct.Padding.html
+    fn padding_vertical(self, padding: f64) -> Padding<T> {
+        Padding::vertical(padding, self)
+    }
+
     /// Wrap this widget in an [`Align`] widget, configured to center it.
     ///
     /// [`Align`]: widget/struct.Align.html
@@ -68,22 +91,48 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
 
     /// Wrap this widget in a [`SizedBox`] with an explicit width.
     ///
+    /// The width can be either an `f64` or a [`Key<f64>`], which is resolved
+    /// in the [`Env`] during layout, so that the size can follow the theme:
+    ///
+    /// ```
+    /// use druid::widget::Button;
+    /// use druid::{theme, WidgetExt};
+    ///
+    /// let ok = Button::<()>::new("OK").fix_width(theme::WIDE_WIDGET_WIDTH);
+    /// let cancel = Button::<()>::new("Cancel").fix_width(80.0);
+    /// ```
+    ///
     /// [`SizedBox`]: widget/struct.SizedBox.html
-    fn fix_width(self, width: f64) -> SizedBox<T> {
+    /// [`Key<f64>`]: struct.Key.html
+    /// [`Env`]: struct.Env.html
+    fn fix_width(self, width: impl Into<KeyOrValue<f64>>) -> SizedBox<T> {
         SizedBox::new(self).width(width)
     }
 
-    /// Wrap this widget in a [`SizedBox`] with an explicit width.
+    /// Wrap this widget in a [`SizedBox`] with an explicit height.
+    ///
+    /// The height can be either an `f64` or a [`Key<f64>`], as with
+    /// [`fix_width`].
     ///
     /// [`SizedBox`]: widget/struct.SizedBox.html
-    fn fix_height(self, height: f64) -> SizedBox<T> {
+    /// [`Key<f64>`]: struct.Key.html
+    /// [`fix_width`]: #method.fix_width
+    fn fix_height(self, height: impl Into<KeyOrValue<f64>>) -> SizedBox<T> {
         SizedBox::new(self).height(height)
     }
 
-    /// Wrap this widget in an [`SizedBox`] with an explicit width and height
+    /// Wrap this widget in a [`SizedBox`] with an explicit width and height.
+    ///
+    /// Both can be either an `f64` or a [`Key<f64>`], as with [`fix_width`].
     ///
     /// [`SizedBox`]: widget/struct.SizedBox.html
-    fn fix_size(self, width: f64, height: f64) -> SizedBox<T> {
+    /// [`Key<f64>`]: struct.Key.html
+    /// [`fix_width`]: #method.fix_width
+    fn fix_size(
+        self,
+        width: impl Into<KeyOrValue<f64>>,
+        height: impl Into<KeyOrValue<f64>>,
+    ) -> SizedBox<T> {
         SizedBox::new(self).width(width).height(height)
     }
 
@@ -118,6 +167,26 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
         SizedBox::new(self).expand_height()
     }
 
+    /// Wrap this widget in a vertically scrolling [`Scroll`].
+    ///
+    /// The widget is given the viewport's width, and can be as tall as it
+    /// wants.
+    ///
+    /// [`Scroll`]: widget/struct.Scroll.html
+    fn scroll(self) -> Scroll<T, Self> {
+        Scroll::new(self).vertical()
+    }
+
+    /// Wrap this widget in a horizontally scrolling [`Scroll`].
+    ///
+    /// The widget is given the viewport's height, and can be as wide as it
+    /// wants.
+    ///
+    /// [`Scroll`]: widget/struct.Scroll.html
+    fn scroll_horizontal(self) -> Scroll<T, Self> {
+        Scroll::new(self).horizontal()
+    }
+
     /// Wrap this widget in a [`Container`] with the provided `background`.
     ///
     /// See [`Container::background`] for more information.
@@ -152,6 +221,19 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
         EnvScope::new(f, self)
     }
 
+    /// Wrap this widget in a [`StateScope`] widget, modifying the parent
+    /// [`Env`] with the provided closure, depending on whether the widget is
+    /// hot or active.
+    ///
+    /// [`StateScope`]: widget/struct.StateScope.html
+    /// [`Env`]: struct.Env.html
+    fn state_scope(
+        self,
+        f: impl Fn(&mut Env, &T, InteractionState) + 'static,
+    ) -> StateScope<T, Self> {
+        StateScope::new(f, self)
+    }
+
     /// Wrap this widget with the provided [`Controller`].
     ///
     /// [`Controller`]: widget/trait.Controller.html
@@ -176,9 +258,140 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
         ControllerHost::new(self, Click::new(f))
     }
 
+    /// Call the provided closure once, right after this widget is added to
+    /// the widget tree, using an [`OnAdded`] controller.
+    ///
+    /// This is useful for one-time initialization, like requesting focus or
+    /// submitting a command to start loading something. The closure can also
+    /// change the data, and gets the [`EventCtx`] just like [`on_click`].
+    ///
+    /// [`EventCtx`]: struct.EventCtx.html
+    /// [`on_click`]: #method.on_click
+    /// [`OnAdded`]: widget/struct.OnAdded.html
+    fn on_added(
+        self,
+        f: impl Fn(&mut EventCtx, &mut T, &Env) + 'static,
+    ) -> ControllerHost<Self, OnAdded<T>> {
+        ControllerHost::new(self, OnAdded::new(f))
+    }
+
+    /// Submit `command` when `hotkey` is pressed, using a [`Shortcut`]
+    /// controller.
+    ///
+    /// [`Shortcut`]: widget/struct.Shortcut.html
+    fn on_hotkey(
+        self,
+        hotkey: HotKey,
+        command: impl Into<Command>,
+    ) -> ControllerHost<Self, Shortcut> {
+        ControllerHost::new(self, Shortcut::new(hotkey, command))
+    }
+
+    /// Call the provided closure once the data of this widget has stopped
+    /// changing for `delay`, using a [`Debounce`] controller.
+    ///
+    /// [`Debounce`]: widget/struct.Debounce.html
+    fn debounce(
+        self,
+        delay: Duration,
+        f: impl Fn(&T, &Env) + 'static,
+    ) -> ControllerHost<Self, Debounce<T>> {
+        ControllerHost::new(self, Debounce::new(delay, f))
+    }
+
+    /// Let the user drag a payload out of this widget, using a [`DragSource`]
+    /// controller.
+    ///
+    /// The payload is computed from the data when the drag starts, and can be
+    /// dropped onto a widget wrapped with [`drop_target`].
+    ///
+    /// [`DragSource`]: widget/struct.DragSource.html
+    /// [`drop_target`]: #method.drop_target
+    fn draggable<P: Any>(
+        self,
+        payload: impl Fn(&T, &Env) -> P + 'static,
+    ) -> ControllerHost<Self, DragSource<T, P>> {
+        ControllerHost::new(self, DragSource::new(payload))
+    }
+
+    /// Wrap this widget in a [`DropTarget`], which calls `on_drop` when a
+    /// payload from a [`draggable`] widget that `accept` agrees to is
+    /// dropped onto it.
+    ///
+    /// ```
+    /// use druid::widget::{Flex, Label};
+    /// use druid::WidgetExt;
+    ///
+    /// // drag the top label onto the bottom one to add 1
+    /// let adder = Flex::<u32>::column()
+    ///     .with_child(Label::new("+1").draggable(|_data, _env| 1u32))
+    ///     .with_child(
+    ///         Label::dynamic(|data: &u32, _env| data.to_string())
+    ///             .drop_target(|_: &u32, _data| true, |_ctx, n, data, _env| *data += n),
+    ///     );
+    /// ```
+    ///
+    /// [`DropTarget`]: widget/struct.DropTarget.html
+    /// [`draggable`]: #method.draggable
+    fn drop_target<P: Any + Clone>(
+        self,
+        accept: impl Fn(&P, &T) -> bool + 'static,
+        on_drop: impl Fn(&mut EventCtx, &P, &mut T, &Env) + 'static,
+    ) -> DropTarget<T, P, Self> {
+        DropTarget::new(self, accept, on_drop)
+    }
+
+    /// Wrap this widget in a [`FocusRing`], which paints a ring around it
+    /// while it has keyboard focus.
+    ///
+    /// [`FocusRing`]: widget/struct.FocusRing.html
+    fn focus_ring(self) -> FocusRing<T, Self> {
+        FocusRing::new(self)
+    }
+
+    /// Wrap this widget in a [`DisabledIf`] widget, which disables it
+    /// whenever the provided closure returns `true`.
+    ///
+    /// A disabled widget receives no clicks or keyboard events, and is painted
+    /// dimmed by [`theme::DISABLED_DIM_FACTOR`].
+    ///
+    /// [`DisabledIf`]: widget/struct.DisabledIf.html
+    /// [`theme::DISABLED_DIM_FACTOR`]: theme/constant.DISABLED_DIM_FACTOR.html
+    fn disabled_if(self, disabled_if: impl Fn(&T, &Env) -> bool + 'static) -> DisabledIf<T, Self> {
+        DisabledIf::new(self, disabled_if)
+    }
+
+    /// Wrap this widget in a [`FadeToBackground`] widget, which fades it
+    /// towards the window background color, to the given opacity.
+    ///
+    /// This is only an approximation of transparency, which looks right as
+    /// long as the widget sits directly on the window background.
+    ///
+    /// [`FadeToBackground`]: widget/struct.FadeToBackground.html
+    fn fade_to_background(self, opacity: f64) -> FadeToBackground<T, Self> {
+        FadeToBackground::new(self, opacity)
+    }
+
+    /// Wrap this widget in a [`Tooltip`], which shows `text` when the mouse
+    /// rests over this widget.
+    ///
+    /// The delay before the tooltip appears is [`theme::TOOLTIP_DELAY`].
+    ///
+    /// [`Tooltip`]: widget/struct.Tooltip.html
+    /// [`theme::TOOLTIP_DELAY`]: theme/constant.TOOLTIP_DELAY.html
+    fn tooltip(self, text: impl Into<LabelText<T>>) -> Tooltip<T, Self> {
+        Tooltip::new(self, text)
+    }
+
     /// Draw the [`layout`] `Rect`s of  this widget and its children.
     ///
+    /// Each widget's bounds are outlined with a 1px line, in a color derived
+    /// from its [`WidgetId`]. This works by setting [`Env::DEBUG_PAINT`] for
+    /// this part of the tree; set that key yourself to turn it on everywhere.
+    ///
     /// [`layout`]: trait.Widget.html#tymethod.layout
+    /// [`WidgetId`]: struct.WidgetId.html
+    /// [`Env::DEBUG_PAINT`]: struct.Env.html#associatedconstant.DEBUG_PAINT
     fn debug_paint_layout(self) -> EnvScope<T, Self> {
         EnvScope::new(|env, _| env.set(Env::DEBUG_PAINT, true), self)
     }
@@ -221,9 +434,10 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
 
     /// Parse a `Widget<String>`'s contents
     #[deprecated(since = "0.7.0", note = "Use TextBox::with_formatter instead")]
-    fn parse(self) -> Parse<Self>
+    fn parse<U>(self) -> Parse<Self, U>
     where
         Self: Widget<String>,
+        U: std::str::FromStr + std::fmt::Display + 'static,
     {
         Parse::new(self)
     }
@@ -242,6 +456,22 @@ pub trait WidgetExt<T: Data>: Widget<T> + Sized + 'static {
     }
 
     /// Wrap this widget in a `Box`.
+    ///
+    /// This erases the type of the widget, which is useful at the end of a
+    /// chain when several branches need to produce the same type:
+    ///
+    /// ```
+    /// use druid::widget::{Label, Spinner};
+    /// use druid::{Widget, WidgetExt};
+    ///
+    /// fn status(loading: bool) -> Box<dyn Widget<()>> {
+    ///     if loading {
+    ///         Spinner::new().padding(4.0).boxed()
+    ///     } else {
+    ///         Label::new("done").padding(4.0).boxed()
+    ///     }
+    /// }
+    /// ```
     fn boxed(self) -> Box<dyn Widget<T>> {
         Box::new(self)
     }
@@ -255,13 +485,21 @@ impl<T: Data, W: Widget<T> + 'static> WidgetExt<T> for W {}
 
 #[doc(hidden)]
 impl<T: Data> SizedBox<T> {
-    pub fn fix_width(self, width: f64) -> SizedBox<T> {
+    pub fn fix_width(self, width: impl Into<KeyOrValue<f64>>) -> SizedBox<T> {
         self.width(width)
     }
 
-    pub fn fix_height(self, height: f64) -> SizedBox<T> {
+    pub fn fix_height(self, height: impl Into<KeyOrValue<f64>>) -> SizedBox<T> {
         self.height(height)
     }
+
+    pub fn fix_size(
+        self,
+        width: impl Into<KeyOrValue<f64>>,
+        height: impl Into<KeyOrValue<f64>>,
+    ) -> SizedBox<T> {
+        self.width(width).height(height)
+    }
 }
 
 // if two things are modifying an env one after another, just combine the modifications
@@ -317,6 +555,10 @@ mod tests {
         // this should be SizedBox<Slider>
         let widget = Slider::new().fix_height(10.0).fix_width(1.0);
         assert_eq!(widget.width_and_height(), (Some(1.0), Some(10.0)));
+
+        // this should also be SizedBox<Slider>
+        let widget = Slider::new().fix_width(1.0).fix_size(2.0, 3.0);
+        assert_eq!(widget.width_and_height(), (Some(2.0), Some(3.0)));
     }
 
     /// we only care that this will compile; see
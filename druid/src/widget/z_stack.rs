@@ -0,0 +1,157 @@
+// Copyright 2020 The Druid Authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A widget that stacks its children on top of each other.
+
+use crate::widget::prelude::*;
+use crate::{Data, Rect, UnitPoint, WidgetPod};
+
+/// A container that stacks its children on top of each other.
+///
+/// Every child is laid out with the constraints the `ZStack` receives, and the
+/// stack is as large as its largest child. Children that are smaller than the
+/// stack are positioned according to their alignment, which defaults to
+/// [`UnitPoint::CENTER`].
+///
+/// Children are painted in the order they were added, so the last child ends
+/// up on top. Events are delivered in the opposite order: the topmost child
+/// gets the first chance to handle an event, and once an event is
+/// [handled], the children below it will not see it.
+///
+/// # Examples
+///
+/// ```
+/// use druid::widget::{Label, ZStack};
+/// use druid::UnitPoint;
+///
+/// let icon_with_badge: ZStack<()> = ZStack::new()
+///     .with_child(Label::new("📬"))
+///     .with_aligned_child(Label::new("3"), UnitPoint::TOP_RIGHT);
+/// ```
+///
+/// [`UnitPoint::CENTER`]: ../struct.UnitPoint.html#associatedconstant.CENTER
+/// [handled]: ../struct.EventCtx.html#method.set_handled
+pub struct ZStack<T> {
+    children: Vec<ZChild<T>>,
+}
+
+struct ZChild<T> {
+    widget: WidgetPod<T, Box<dyn Widget<T>>>,
+    alignment: UnitPoint,
+}
+
+impl<T: Data> ZStack<T> {
+    /// Create a new, empty `ZStack`.
+    pub fn new() -> Self {
+        ZStack {
+            children: Vec::new(),
+        }
+    }
+
+    /// Builder-style variant of [`add_child`].
+    ///
+    /// [`add_child`]: #method.add_child
+    pub fn with_child(mut self, child: impl Widget<T> + 'static) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    /// Builder-style variant of [`add_aligned_child`].
+    ///
+    /// [`add_aligned_child`]: #method.add_aligned_child
+    pub fn with_aligned_child(
+        mut self,
+        child: impl Widget<T> + 'static,
+        alignment: UnitPoint,
+    ) -> Self {
+        self.add_aligned_child(child, alignment);
+        self
+    }
+
+    /// Add a child on top of the existing children, centered in the stack.
+    pub fn add_child(&mut self, child: impl Widget<T> + 'static) {
+        self.add_aligned_child(child, UnitPoint::CENTER);
+    }
+
+    /// Add a child on top of the existing children, with the given alignment.
+    pub fn add_aligned_child(&mut self, child: impl Widget<T> + 'static, alignment: UnitPoint) {
+        self.children.push(ZChild {
+            widget: WidgetPod::new(Box::new(child)),
+            alignment,
+        });
+    }
+}
+
+impl<T: Data> Default for ZStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Data> Widget<T> for ZStack<T> {
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut T, env: &Env) {
+        // the topmost child gets the first chance to handle the event; the
+        // `WidgetPod`s below will skip it once it is handled.
+        for child in self.children.iter_mut().rev() {
+            child.widget.event(ctx, event, data, env);
+        }
+    }
+
+    fn lifecycle(&mut self, ctx: &mut LifeCycleCtx, event: &LifeCycle, data: &T, env: &Env) {
+        for child in &mut self.children {
+            child.widget.lifecycle(ctx, event, data, env);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateCtx, _old_data: &T, data: &T, env: &Env) {
+        for child in &mut self.children {
+            child.widget.update(ctx, data, env);
+        }
+    }
+
+    fn layout(&mut self, ctx: &mut LayoutCtx, bc: &BoxConstraints, data: &T, env: &Env) -> Size {
+        bc.debug_check("ZStack");
+
+        let mut sizes = Vec::with_capacity(self.children.len());
+        let mut my_size = bc.min();
+        for child in &mut self.children {
+            let size = child.widget.layout(ctx, bc, data, env);
+            my_size.width = my_size.width.max(size.width);
+            my_size.height = my_size.height.max(size.height);
+            sizes.push(size);
+        }
+        let my_size = bc.constrain(my_size);
+
+        let mut paint_rect = my_size.to_rect();
+        for (child, size) in self.children.iter_mut().zip(sizes) {
+            let extra_width = (my_size.width - size.width).max(0.);
+            let extra_height = (my_size.height - size.height).max(0.);
+            let origin = child
+                .alignment
+                .resolve(Rect::new(0., 0., extra_width, extra_height))
+                .expand();
+            child.widget.set_origin(ctx, data, env, origin);
+            paint_rect = paint_rect.union(child.widget.paint_rect());
+        }
+
+        ctx.set_paint_insets(paint_rect - my_size.to_rect());
+        my_size
+    }
+
+    fn paint(&mut self, ctx: &mut PaintCtx, data: &T, env: &Env) {
+        for child in &mut self.children {
+            child.widget.paint(ctx, data, env);
+        }
+    }
+}
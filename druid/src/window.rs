@@ -14,6 +14,7 @@
 
 //! Management of multiple windows.
 
+use std::any::Any;
 use std::collections::{HashMap, VecDeque};
 use std::mem;
 
@@ -54,6 +55,7 @@ pub struct Window<T> {
     pub(crate) focus: Option<WidgetId>,
     pub(crate) handle: WindowHandle,
     pub(crate) timers: HashMap<TimerToken, WidgetId>,
+    drag_payload: Option<Box<dyn Any>>,
     ext_handle: ExtEventSink,
     // delegate?
 }
@@ -78,6 +80,7 @@ impl<T> Window<T> {
             focus: None,
             handle,
             timers: HashMap::new(),
+            drag_payload: None,
             ext_handle,
         }
     }
@@ -208,8 +211,14 @@ impl<T: Data> Window<T> {
 
         let mut widget_state = WidgetState::new(self.root.id(), Some(self.size));
         let is_handled = {
-            let mut state =
-                ContextState::new::<T>(queue, &self.ext_handle, &self.handle, self.id, self.focus);
+            let mut state = ContextState::new::<T>(
+                queue,
+                &self.ext_handle,
+                &self.handle,
+                self.id,
+                self.focus,
+                &mut self.drag_payload,
+            );
             let mut notifications = VecDeque::new();
             let mut ctx = EventCtx {
                 state: &mut state,
@@ -229,6 +238,12 @@ impl<T: Data> Window<T> {
             Handled::from(ctx.is_handled)
         };
 
+        // A drag ends when the mouse is released; every widget that got the
+        // `MouseUp` could still see the payload.
+        if let Event::MouseUp(_) = event {
+            self.drag_payload = None;
+        }
+
         // Clean up the timer token and do it immediately after the event handling
         // because the token may be reused and re-added in a lifecycle pass below.
         if let Event::Internal(InternalEvent::RouteTimer(token, _)) = event {
@@ -269,8 +284,14 @@ impl<T: Data> Window<T> {
         process_commands: bool,
     ) {
         let mut widget_state = WidgetState::new(self.root.id(), Some(self.size));
-        let mut state =
-            ContextState::new::<T>(queue, &self.ext_handle, &self.handle, self.id, self.focus);
+        let mut state = ContextState::new::<T>(
+            queue,
+            &self.ext_handle,
+            &self.handle,
+            self.id,
+            self.focus,
+            &mut self.drag_payload,
+        );
         let mut ctx = LifeCycleCtx {
             state: &mut state,
             widget_state: &mut widget_state,
@@ -283,8 +304,14 @@ impl<T: Data> Window<T> {
         self.update_title(data, env);
 
         let mut widget_state = WidgetState::new(self.root.id(), Some(self.size));
-        let mut state =
-            ContextState::new::<T>(queue, &self.ext_handle, &self.handle, self.id, self.focus);
+        let mut state = ContextState::new::<T>(
+            queue,
+            &self.ext_handle,
+            &self.handle,
+            self.id,
+            self.focus,
+            &mut self.drag_payload,
+        );
         let mut update_ctx = UpdateCtx {
             widget_state: &mut widget_state,
             state: &mut state,
@@ -360,12 +387,19 @@ impl<T: Data> Window<T> {
 
     fn layout(&mut self, queue: &mut CommandQueue, data: &T, env: &Env) {
         let mut widget_state = WidgetState::new(self.root.id(), Some(self.size));
-        let mut state =
-            ContextState::new::<T>(queue, &self.ext_handle, &self.handle, self.id, self.focus);
+        let mut state = ContextState::new::<T>(
+            queue,
+            &self.ext_handle,
+            &self.handle,
+            self.id,
+            self.focus,
+            &mut self.drag_payload,
+        );
         let mut layout_ctx = LayoutCtx {
             state: &mut state,
             widget_state: &mut widget_state,
             mouse_pos: self.last_mouse_pos,
+            skip_cache: false,
         };
         let bc = BoxConstraints::tight(self.size);
         self.root.layout(&mut layout_ctx, &bc, data, env);
@@ -390,8 +424,14 @@ impl<T: Data> Window<T> {
         env: &Env,
     ) {
         let widget_state = WidgetState::new(self.root.id(), Some(self.size));
-        let mut state =
-            ContextState::new::<T>(queue, &self.ext_handle, &self.handle, self.id, self.focus);
+        let mut state = ContextState::new::<T>(
+            queue,
+            &self.ext_handle,
+            &self.handle,
+            self.id,
+            self.focus,
+            &mut self.drag_payload,
+        );
         let mut ctx = PaintCtx {
             render_ctx: piet,
             state: &mut state,